@@ -16,3 +16,56 @@ cargo openvm setup
 # cargo openvm prove evm
 # verify the evm proof
 # cargo openvm verify evm
+
+# stdin-driven suites are behind cargo features, e.g. the sha256 monte carlo test:
+# cargo openvm build --features sha256-monte-carlo
+# cargo openvm run --input inputs/sha256_monte_carlo.json
+#
+# SHA256_TEST_CASES entries from the CAVP byte vectors, e.g. SHA256LongMsg.rsp at Len=1304:
+# python3 scripts/cavp_sha256_cases.py --len 1304 shabytetestvectors/SHA256LongMsg.rsp
+#
+# blob kzg proofs from the consensus-spec-tests general vectors:
+# python3 scripts/kzg_blob_inputs.py path/to/consensus-spec-tests inputs/kzg_blobs.json
+# cargo openvm build --features kzg-blobs
+# cargo openvm run --input inputs/kzg_blobs.json
+#
+# point evaluation under a devnet trusted setup with a known tau:
+# python3 scripts/kzg_devnet_setup.py inputs/kzg_devnet_setup.txt inputs/kzg_devnet.json
+# cargo openvm build --features kzg-custom-setup
+# cargo openvm run --input inputs/kzg_devnet.json
+#
+# groth16 proofs in snarkjs format, verified like the snarkjs solidity verifier.
+# Real snarkjs proofs are not checked in: prove scripts/groth16/multiplier.circom with circom and snarkjs,
+# or pack existing snarkjs files, then run them:
+# scripts/groth16/prove.sh inputs/groth16.json
+# python3 scripts/groth16_inputs.py verification_key.json proof.json public.json inputs/groth16.json
+# cargo openvm build --features groth16
+# cargo openvm run --input inputs/groth16.json
+# inputs/groth16_simulated.json only checks self-consistency: its key and proof come from a simulated
+# setup with a known trapdoor, not from snarkjs.
+#
+# captured WebAuthn ES256 assertions, e.g. SimpleWebAuthn or webauthn-rs test fixtures, in the
+# PublicKeyCredential.toJSON() shape with the credential's COSE public key:
+# python3 scripts/webauthn_inputs.py fixture.json... inputs/webauthn.json
+# cargo openvm build --features webauthn-fixtures
+# cargo openvm run --input inputs/webauthn.json
+#
+# replay of recorded precompile calls extracted from geth `debug_traceTransaction` callTracer dumps:
+# python3 scripts/geth_trace_extract.py --spec CANCUN trace.json >> inputs/trace.jsonl
+# python3 scripts/trace_inputs.py inputs/trace.jsonl inputs/trace.json
+# cargo openvm build --features precompile-trace
+# cargo openvm run --input inputs/trace.json
+#
+# ecPairing cycles from 1 to 128 pairs, on inputs generated in the guest:
+# cargo openvm build --features pairing-scaling
+# cargo openvm run
+#
+# modexp cycles of every vector on revm's software bignum and on the openvm chips:
+# cargo openvm build --features modexp-backends
+# cargo openvm run
+#
+# Wycheproof rsa pkcs#1 v1.5 sha256 signatures from a Wycheproof checkout, `--flag <flag>` keeps
+# valid tests and tests with that flag only:
+# python3 scripts/wycheproof_rsa_inputs.py wycheproof/testvectors_v1/rsa_signature_{2048,3072,4096}_sha256_test.json inputs/rsa_wycheproof.json
+# cargo openvm build --features rsa-wycheproof
+# cargo openvm run --input inputs/rsa_wycheproof.json
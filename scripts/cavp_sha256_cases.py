@@ -0,0 +1,54 @@
+#!/usr/bin/env python3
+"""Convert NIST CAVP SHA-256 byte-oriented vectors into `SHA256_TEST_CASES` entries.
+
+Reads SHA256ShortMsg.rsp or SHA256LongMsg.rsp from the CAVP shabytetestvectors archive and
+prints one Rust tuple per selected message, preceded by its `<file>#Len=<bits>` source comment,
+ready to be pasted into `src/sha256.rs`. Each digest is checked against hashlib first.
+
+With --len, only the given message lengths in bits are printed, e.g. Len=1304 and a
+multi-block length of SHA256LongMsg.rsp.
+
+usage: cavp_sha256_cases.py [--len BITS]... <rsp file>
+"""
+
+import argparse
+import hashlib
+import os
+
+
+def parse_rsp(path: str):
+    case = {}
+    with open(path) as f:
+        for line in f:
+            line = line.strip()
+            if not line or line.startswith("#") or line.startswith("["):
+                continue
+            key, _, value = line.partition("=")
+            case[key.strip()] = value.strip()
+            if key.strip() == "MD":
+                yield int(case["Len"]), case["Msg"], case["MD"]
+                case = {}
+
+
+def main():
+    parser = argparse.ArgumentParser()
+    parser.add_argument("--len", type=int, action="append", dest="lengths")
+    parser.add_argument("rsp")
+    args = parser.parse_args()
+
+    name = os.path.basename(args.rsp)
+    for bits, msg, md in parse_rsp(args.rsp):
+        if args.lengths and bits not in args.lengths:
+            continue
+        # Len=0 is written as Msg = 00
+        msg = msg[: bits // 4]
+        assert hashlib.sha256(bytes.fromhex(msg)).hexdigest() == md.lower(), f"Len={bits}"
+        print(f'    // "NIST CAVP {name}#Len={bits}"')
+        print("    (")
+        print(f'        &hex!("{msg}"),')
+        print(f'        hex!("{md.lower()}"),')
+        print("    ),")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,90 @@
+#!/usr/bin/env python3
+"""Extract precompile calls from geth `debug_traceTransaction` callTracer dumps as trace JSONL.
+
+Accepts either the bare callTracer result or a JSON-RPC response with it under `result`,
+and walks every nested call frame. Frames whose `to` is a precompile address become one
+JSONL line each, in the format read by `src/trace.rs`:
+
+    {"spec": "CANCUN", "address": "0x...01", "input": "0x...", "expected_output": "0x...", "gas": 3000, "gas_used": 3000}
+
+A callTracer dump does not name the fork, so the revm precompile spec of the block is given with --spec.
+
+Failed calls carry geth's `error` string instead of an output and gas used.
+
+Calls to the BLS12-381 (0x0b to 0x11) and P256VERIFY (0x100) precompiles are not replayed by
+`src/trace.rs`; they are skipped with a warning on stderr.
+
+usage: geth_trace_extract.py --spec <spec> <callTracer json>... >> trace.jsonl
+"""
+
+import argparse
+import json
+import sys
+from collections import Counter
+
+# 0x01 ecrecover to 0x0a point evaluation
+PRECOMPILES = set(range(0x01, 0x0B))
+
+# precompiles of later forks and rollups that are skipped with a warning
+UNSUPPORTED_PRECOMPILES = {
+    **{address: "bls12-381" for address in range(0x0B, 0x12)},
+    0x100: "p256verify",
+}
+
+# precompile spec ids of the revm fork, see `precompile_spec` in `src/trace.rs`
+SPECS = ["HOMESTEAD", "BYZANTIUM", "ISTANBUL", "BERLIN", "CANCUN", "PRAGUE", "LATEST"]
+
+
+def precompile_calls(frame, skipped):
+    """Yield the precompile call frames under `frame`, depth first in execution order.
+
+    Calls to unsupported precompiles are counted by address in `skipped`.
+    """
+    to = frame.get("to")
+    if to is not None:
+        address = int(to, 16)
+        if address in PRECOMPILES:
+            yield frame
+        elif address in UNSUPPORTED_PRECOMPILES:
+            skipped[address] += 1
+    for call in frame.get("calls", []):
+        yield from precompile_calls(call, skipped)
+
+
+def trace_line(spec, frame):
+    line = {
+        "spec": spec,
+        "address": "0x" + int(frame["to"], 16).to_bytes(20, "big").hex(),
+        "input": frame.get("input", "0x"),
+        "gas": int(frame["gas"], 16),
+    }
+    if "error" in frame:
+        line["error"] = frame["error"]
+    else:
+        line["expected_output"] = frame.get("output", "0x")
+        line["gas_used"] = int(frame["gasUsed"], 16)
+    return line
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__, formatter_class=argparse.RawDescriptionHelpFormatter)
+    parser.add_argument("--spec", required=True, choices=SPECS, help="precompile spec of the traced block")
+    parser.add_argument("traces", nargs="+", type=argparse.FileType())
+    args = parser.parse_args()
+
+    for trace in args.traces:
+        root = json.load(trace)
+        root = root.get("result", root)
+        count = 0
+        skipped = Counter()
+        for frame in precompile_calls(root, skipped):
+            print(json.dumps(trace_line(args.spec, frame)))
+            count += 1
+        print(f"{trace.name}: {count} precompile calls", file=sys.stderr)
+        for address, calls in sorted(skipped.items()):
+            name = UNSUPPORTED_PRECOMPILES[address]
+            print(f"warning: {trace.name}: skipped {calls} calls to {name} at {address:#x}", file=sys.stderr)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,11 @@
+pragma circom 2.0.0;
+
+// c = a * b with a known to the prover only, so the proof has two public signals: c and b
+template Multiplier() {
+    signal input a;
+    signal input b;
+    signal output c;
+    c <== a * b;
+}
+
+component main {public [b]} = Multiplier();
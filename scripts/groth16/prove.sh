@@ -0,0 +1,29 @@
+#!/usr/bin/env bash
+# Build the multiplier circuit, run a local groth16 setup and prove one witness with circom and snarkjs,
+# then pack the snarkjs files into inputs/groth16.json.
+# Requires circom 2 and snarkjs (npm install -g snarkjs) on the PATH.
+#
+# usage: scripts/groth16/prove.sh [output json]
+set -euo pipefail
+
+here="$(cd "$(dirname "$0")" && pwd)"
+output="${1:-$here/../../inputs/groth16.json}"
+build="$(mktemp -d)"
+trap 'rm -rf "$build"' EXIT
+
+circom "$here/multiplier.circom" --r1cs --wasm -o "$build"
+
+# the setup is local and single-party, fine for tests, never for deployments
+snarkjs powersoftau new bn128 8 "$build/pot_0000.ptau"
+snarkjs powersoftau contribute "$build/pot_0000.ptau" "$build/pot_0001.ptau" --name=test -e=precompile-tests
+snarkjs powersoftau prepare phase2 "$build/pot_0001.ptau" "$build/pot_final.ptau"
+snarkjs groth16 setup "$build/multiplier.r1cs" "$build/pot_final.ptau" "$build/multiplier_0000.zkey"
+snarkjs zkey contribute "$build/multiplier_0000.zkey" "$build/multiplier.zkey" --name=test -e=precompile-tests
+snarkjs zkey export verificationkey "$build/multiplier.zkey" "$build/verification_key.json"
+
+echo '{"a": "3", "b": "11"}' > "$build/input.json"
+snarkjs groth16 fullprove "$build/input.json" "$build/multiplier_js/multiplier.wasm" "$build/multiplier.zkey" \
+    "$build/proof.json" "$build/public.json"
+snarkjs groth16 verify "$build/verification_key.json" "$build/public.json" "$build/proof.json"
+
+python3 "$here/../groth16_inputs.py" "$build/verification_key.json" "$build/proof.json" "$build/public.json" "$output"
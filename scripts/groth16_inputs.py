@@ -0,0 +1,88 @@
+#!/usr/bin/env python3
+"""Pack a snarkjs Groth16 verification key, proof and public inputs into an openvm stdin file.
+
+Besides the given proof, tampered variants are added that every verifier must reject.
+The stream layout expected by `src/groth16.rs` is:
+
+    verification_key.json
+    count (u32 le)
+    count x [proof.json, public.json, expected (1 byte: 1 valid, 0 invalid)]
+
+usage: groth16_inputs.py <verification_key.json> <proof.json> <public.json> <output json>
+"""
+
+import argparse
+import copy
+import json
+import struct
+
+# bn254 base field and scalar field moduli
+Q = 21888242871839275222246405745257275088696311157297823662689037894645226208583
+R = 21888242871839275222246405745257275088548364400416034343698204186575808495617
+
+
+def item(data: bytes) -> str:
+    # openvm input items are hex strings with a 0x01 prefix for raw bytes
+    return "0x01" + data.hex()
+
+
+def as_json(value) -> bytes:
+    return json.dumps(value, separators=(",", ":")).encode()
+
+
+def tampered_cases(proof, public):
+    """(description, proof, public) variants of a valid proof that must not verify."""
+    cases = []
+    if public:
+        changed = list(public)
+        changed[0] = str((int(changed[0]) + 1) % R)
+        cases.append(("first public input incremented", proof, changed))
+
+        # the verifier must reject public inputs that are not reduced modulo r
+        aliased = list(public)
+        aliased[0] = str(int(aliased[0]) + R)
+        cases.append(("first public input plus r", proof, aliased))
+
+    negated = copy.deepcopy(proof)
+    negated["pi_a"][1] = str((Q - int(proof["pi_a"][1])) % Q)
+    cases.append(("A negated", negated, public))
+
+    swapped = copy.deepcopy(proof)
+    swapped["pi_a"], swapped["pi_c"] = proof["pi_c"], proof["pi_a"]
+    cases.append(("A and C swapped", swapped, public))
+
+    # swapping the Fp2 coefficients of B.x almost surely gives a point off the twist, so ecPairing fails
+    conjugated = copy.deepcopy(proof)
+    conjugated["pi_b"][0] = proof["pi_b"][0][::-1]
+    cases.append(("coefficients of B.x swapped", conjugated, public))
+
+    off_curve = copy.deepcopy(proof)
+    off_curve["pi_c"][1] = str((int(proof["pi_c"][1]) + 1) % Q)
+    cases.append(("C not on the curve", off_curve, public))
+    return cases
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__, formatter_class=argparse.RawDescriptionHelpFormatter)
+    parser.add_argument("verification_key", type=argparse.FileType())
+    parser.add_argument("proof", type=argparse.FileType())
+    parser.add_argument("public", type=argparse.FileType())
+    parser.add_argument("output", type=argparse.FileType("w"))
+    args = parser.parse_args()
+
+    verification_key = json.load(args.verification_key)
+    proof = json.load(args.proof)
+    public = json.load(args.public)
+
+    cases = [("valid proof", proof, public, True)]
+    cases += [(description, p, inputs, False) for description, p, inputs in tampered_cases(proof, public)]
+
+    stream = [item(as_json(verification_key)), item(struct.pack("<I", len(cases)))]
+    for description, p, inputs, valid in cases:
+        print(f"{'valid' if valid else 'invalid'}: {description}")
+        stream += [item(as_json(p)), item(as_json(inputs)), item(bytes([valid]))]
+    json.dump({"input": stream}, args.output, indent=2)
+
+
+if __name__ == "__main__":
+    main()
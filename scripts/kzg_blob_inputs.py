@@ -0,0 +1,91 @@
+#!/usr/bin/env python3
+"""Convert consensus-spec-tests blob KZG vectors into an openvm stdin file.
+
+Reads `verify_blob_kzg_proof` and `verify_blob_kzg_proof_batch` cases from
+`tests/general/<fork>/kzg/` of a consensus-spec-tests checkout and writes the
+stream layout expected by `src/kzg_blob.rs`:
+
+    count (u32 le)
+    count x [blob, commitment, proof, expected]
+    count (u32 le)
+    count x [number of blobs (u32 le), blobs x [blob, commitment, proof], expected]
+
+where `expected` is one byte: 0 for `false`, 1 for `true` and 2 for `null`.
+
+usage: kzg_blob_inputs.py <consensus-spec-tests dir> <output json> [--fork deneb] [--limit N]
+"""
+
+import argparse
+import json
+import struct
+from pathlib import Path
+
+import yaml
+
+EXPECTED = {False: 0, True: 1, None: 2}
+
+
+def item(data: bytes) -> str:
+    # openvm input items are hex strings with a 0x01 prefix for raw bytes
+    return "0x01" + data.hex()
+
+
+def hex_bytes(value: str) -> bytes:
+    return bytes.fromhex(value.removeprefix("0x"))
+
+
+def count(n: int) -> str:
+    return item(struct.pack("<I", n))
+
+
+def load_cases(root: Path, fork: str, handler: str, limit: int | None):
+    suite = root / "tests" / "general" / fork / "kzg" / handler / "kzg-mainnet"
+    cases = sorted(suite.iterdir())
+    if not cases:
+        raise SystemExit(f"no cases found in {suite}")
+    for case in cases[:limit]:
+        with open(case / "data.yaml") as f:
+            yield case.name, yaml.safe_load(f)
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__, formatter_class=argparse.RawDescriptionHelpFormatter)
+    parser.add_argument("tests", type=Path, help="consensus-spec-tests checkout")
+    parser.add_argument("output", type=Path, help="openvm input json to write")
+    parser.add_argument("--fork", default="deneb")
+    parser.add_argument("--limit", type=int, default=None, help="maximum cases per handler")
+    args = parser.parse_args()
+
+    stream = []
+
+    single = list(load_cases(args.tests, args.fork, "verify_blob_kzg_proof", args.limit))
+    stream.append(count(len(single)))
+    for _, data in single:
+        case = data["input"]
+        stream += [item(hex_bytes(case[key])) for key in ("blob", "commitment", "proof")]
+        stream.append(item(bytes([EXPECTED[data["output"]]])))
+
+    batch = []
+    for name, data in load_cases(args.tests, args.fork, "verify_blob_kzg_proof_batch", args.limit):
+        case = data["input"]
+        lengths = {len(case["blobs"]), len(case["commitments"]), len(case["proofs"])}
+        if len(lengths) != 1:
+            # mismatched list lengths cannot be expressed as a list of blob inputs
+            print(f"skipping {name}: blob, commitment and proof counts differ")
+            continue
+        batch.append(data)
+    stream.append(count(len(batch)))
+    for data in batch:
+        case = data["input"]
+        stream.append(count(len(case["blobs"])))
+        for blob, commitment, proof in zip(case["blobs"], case["commitments"], case["proofs"]):
+            stream += [item(hex_bytes(blob)), item(hex_bytes(commitment)), item(hex_bytes(proof))]
+        stream.append(item(bytes([EXPECTED[data["output"]]])))
+
+    print(f"{len(single)} verify_blob_kzg_proof cases, {len(batch)} verify_blob_kzg_proof_batch cases")
+    with open(args.output, "w") as f:
+        json.dump({"input": stream}, f, indent=2)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,223 @@
+#!/usr/bin/env python3
+"""Generate an insecure devnet KZG trusted setup from a known tau, with point evaluation vectors.
+
+Writes two files:
+- a trusted setup in the c-kzg text format (G1 Lagrange points, G2 monomial points,
+  G1 monomial points), usable by devnet clients;
+- an openvm stdin file for the `kzg-custom-setup` feature of this crate, with the stream layout
+  expected by `run_kzg_custom_setup_tests` in `src/kzg_point_evaluation.rs`:
+
+      G1 Lagrange points (4096 x 48 bytes, concatenated)
+      G2 monomial points (65 x 96 bytes, concatenated)
+      count (u32 le)
+      count x [precompile input (192 bytes), expected (1 byte: 1 valid, 0 invalid)]
+
+Since tau is known, commitments and proofs are computed directly as p(tau) * G1 and
+(p(tau) - y) / (tau - z) * G1 instead of multi-scalar multiplications.
+Computing the 4096 Lagrange points takes a couple of minutes in pure Python.
+
+usage: kzg_devnet_setup.py <setup txt> <input json> [--tau HEX]
+"""
+
+import argparse
+import hashlib
+import json
+import struct
+
+P = 0x1A0111EA397FE69A4B1BA7B6434BACD764774B84F38512BF6730D2A0F6B0F6241EABFFFEB153FFFFB9FEFFFFFFFFAAAB
+R = 0x73EDA753299D7D483339D80809A1D80553BDA402FFFE5BFEFFFFFFFF00000001
+FIELD_ELEMENTS_PER_BLOB = 4096
+NUM_G2_POINTS = 65
+PRIMITIVE_ROOT_OF_UNITY = 7
+
+G1 = (
+    0x17F1D3A73197D7942695638C4FA9AC0FC3688C4F9774B905A14E3A3F171BAC586C55E83FF97A1AEFFB3AF00ADB22C6BB,
+    0x08B3F481E3AAA0F1A09E30ED741D8AE4FCF5E095D5D00AF600DB18CB2C04B3EDD03CC744A2888AE40CAA232946C5E7E1,
+)
+G2 = (
+    (
+        0x024AA2B2F08F0A91260805272DC51051C6E47AD4FA403B02B4510B647AE3D1770BAC0326A805BBEFD48056C8C121BDB8,
+        0x13E02B6052719F607DACD3A088274F65596BD0D09920B61AB5DA61BBDC7F5049334CF11213945D57E5AC7D055D042B7E,
+    ),
+    (
+        0x0CE5D527727D6E118CC9CDC6DA2E351AADFD9BAA8CBDD3A76D429A695160D12C923AC9CC3BACA289E193548608B82801,
+        0x0606C4A02EA734CC32ACD2B02BC28B99CB3E287E85A763AF267492AB572E99AB3F370D275CEC1DA1AAA9075FF05F79BE,
+    ),
+)
+
+# default devnet tau; anyone can forge proofs for this setup, never use it outside tests
+DEFAULT_TAU = int.from_bytes(hashlib.sha256(b"openvm-tests devnet kzg tau").digest(), "big") % R
+
+
+class Fp:
+    """Prime field arithmetic, also used as the base of Fp2."""
+
+    @staticmethod
+    def add(a, b):
+        return (a + b) % P
+
+    @staticmethod
+    def sub(a, b):
+        return (a - b) % P
+
+    @staticmethod
+    def mul(a, b):
+        return a * b % P
+
+    @staticmethod
+    def inv(a):
+        return pow(a, -1, P)
+
+    zero, one = 0, 1
+
+
+class Fp2:
+    """Fp[u] / (u^2 + 1), elements as (c0, c1)."""
+
+    @staticmethod
+    def add(a, b):
+        return ((a[0] + b[0]) % P, (a[1] + b[1]) % P)
+
+    @staticmethod
+    def sub(a, b):
+        return ((a[0] - b[0]) % P, (a[1] - b[1]) % P)
+
+    @staticmethod
+    def mul(a, b):
+        return ((a[0] * b[0] - a[1] * b[1]) % P, (a[0] * b[1] + a[1] * b[0]) % P)
+
+    @staticmethod
+    def inv(a):
+        norm = pow(a[0] * a[0] + a[1] * a[1], -1, P)
+        return (a[0] * norm % P, -a[1] * norm % P)
+
+    zero, one = (0, 0), (1, 0)
+
+
+def point_add(field, a, b):
+    """Affine short Weierstrass addition, None is the point at infinity."""
+    if a is None:
+        return b
+    if b is None:
+        return a
+    if a[0] == b[0]:
+        if field.add(a[1], b[1]) == field.zero:
+            return None
+        x_squared = field.mul(a[0], a[0])
+        slope = field.mul(field.add(field.add(x_squared, x_squared), x_squared), field.inv(field.add(a[1], a[1])))
+    else:
+        slope = field.mul(field.sub(b[1], a[1]), field.inv(field.sub(b[0], a[0])))
+    x = field.sub(field.sub(field.mul(slope, slope), a[0]), b[0])
+    return (x, field.sub(field.mul(slope, field.sub(a[0], x)), a[1]))
+
+
+def point_mul(field, scalar, point):
+    result = None
+    while scalar:
+        if scalar & 1:
+            result = point_add(field, result, point)
+        point = point_add(field, point, point)
+        scalar >>= 1
+    return result
+
+
+def compress_g1(point):
+    if point is None:
+        return bytes([0xC0]) + bytes(47)
+    encoded = bytearray(point[0].to_bytes(48, "big"))
+    encoded[0] |= 0x80
+    if point[1] > (P - 1) // 2:
+        encoded[0] |= 0x20
+    return bytes(encoded)
+
+
+def compress_g2(point):
+    if point is None:
+        return bytes([0xC0]) + bytes(95)
+    (x0, x1), (y0, y1) = point
+    encoded = bytearray(x1.to_bytes(48, "big") + x0.to_bytes(48, "big"))
+    encoded[0] |= 0x80
+    if (y1 if y1 != 0 else y0) > (P - 1) // 2:
+        encoded[0] |= 0x20
+    return bytes(encoded)
+
+
+def g1(scalar):
+    return point_mul(Fp, scalar % R, G1)
+
+
+def g2(scalar):
+    return point_mul(Fp2, scalar % R, G2)
+
+
+def lagrange_scalars(tau):
+    """L_i(tau) over the roots of unity of the blob domain, in natural order as in the c-kzg file."""
+    n = FIELD_ELEMENTS_PER_BLOB
+    omega = pow(PRIMITIVE_ROOT_OF_UNITY, (R - 1) // n, R)
+    vanishing = (pow(tau, n, R) - 1) % R
+    scalars = []
+    root = 1
+    for _ in range(n):
+        # L_i(x) = w^i / n * (x^n - 1) / (x - w^i)
+        scalars.append(root * vanishing * pow(n * (tau - root), -1, R) % R)
+        root = root * omega % R
+    return scalars
+
+
+def field_element(label):
+    return int.from_bytes(hashlib.sha256(label.encode()).digest(), "big") % R
+
+
+def versioned_hash(commitment):
+    return b"\x01" + hashlib.sha256(commitment).digest()[1:]
+
+
+def point_evaluation_vectors(tau):
+    """(input, valid) pairs for polynomials given by their coefficients."""
+    vectors = []
+    for i in range(4):
+        coefficients = [field_element(f"openvm-tests devnet kzg p{i} c{j}") for j in range(i + 1)]
+        z = field_element(f"openvm-tests devnet kzg z{i}")
+        y = sum(c * pow(z, j, R) for j, c in enumerate(coefficients)) % R
+        p_tau = sum(c * pow(tau, j, R) for j, c in enumerate(coefficients)) % R
+        commitment = compress_g1(g1(p_tau))
+        proof = compress_g1(g1((p_tau - y) * pow(tau - z, -1, R)))
+        header = versioned_hash(commitment) + z.to_bytes(32, "big")
+        vectors.append((header + y.to_bytes(32, "big") + commitment + proof, True))
+        # wrong evaluation for the same commitment and proof
+        wrong_y = ((y + 1) % R).to_bytes(32, "big")
+        vectors.append((header + wrong_y + commitment + proof, False))
+    return vectors
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__, formatter_class=argparse.RawDescriptionHelpFormatter)
+    parser.add_argument("setup", help="trusted setup txt to write")
+    parser.add_argument("input", help="openvm input json to write")
+    parser.add_argument("--tau", type=lambda value: int(value, 16) % R, default=DEFAULT_TAU)
+    args = parser.parse_args()
+    tau = args.tau
+
+    g1_lagrange = [compress_g1(g1(scalar)) for scalar in lagrange_scalars(tau)]
+    g2_monomial = [compress_g2(g2(pow(tau, i, R))) for i in range(NUM_G2_POINTS)]
+    g1_monomial = [compress_g1(g1(pow(tau, i, R))) for i in range(FIELD_ELEMENTS_PER_BLOB)]
+
+    with open(args.setup, "w") as f:
+        f.write(f"{FIELD_ELEMENTS_PER_BLOB}\n{NUM_G2_POINTS}\n")
+        for point in g1_lagrange + g2_monomial + g1_monomial:
+            f.write(point.hex() + "\n")
+
+    vectors = point_evaluation_vectors(tau)
+    stream = [
+        "0x01" + b"".join(g1_lagrange).hex(),
+        "0x01" + b"".join(g2_monomial).hex(),
+        "0x01" + struct.pack("<I", len(vectors)).hex(),
+    ]
+    for data, valid in vectors:
+        stream += ["0x01" + data.hex(), "0x01" + bytes([valid]).hex()]
+    with open(args.input, "w") as f:
+        json.dump({"input": stream}, f, indent=2)
+
+
+if __name__ == "__main__":
+    main()
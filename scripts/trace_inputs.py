@@ -0,0 +1,31 @@
+#!/usr/bin/env python3
+"""Pack a precompile call trace (JSONL) into an openvm stdin file.
+
+Each line of the trace is a JSON object with the precompile `spec` the call runs under,
+the precompile `address`, the call `input` and its `expected_output` as 0x-prefixed hex,
+the `gas` made available to the call, and optionally the `gas_used` by a successful call
+or the `error` of a failed one, see `src/trace.rs`.
+The whole file is passed as one input item.
+
+usage: trace_inputs.py <trace jsonl> <output json>
+"""
+
+import argparse
+import json
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__, formatter_class=argparse.RawDescriptionHelpFormatter)
+    parser.add_argument("trace", type=argparse.FileType("rb"))
+    parser.add_argument("output", type=argparse.FileType("w"))
+    args = parser.parse_args()
+
+    trace = args.trace.read()
+    calls = [json.loads(line) for line in trace.splitlines() if line.strip()]
+    print(f"{len(calls)} precompile calls")
+    # openvm input items are hex strings with a 0x01 prefix for raw bytes
+    json.dump({"input": ["0x01" + trace.hex()]}, args.output, indent=2)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,133 @@
+#!/usr/bin/env python3
+"""Pack captured WebAuthn ES256 assertions into an openvm stdin file.
+
+Each fixture is a JSON file holding the assertion in the W3C `AuthenticationResponseJSON` shape
+returned by `PublicKeyCredential.toJSON()` and stored by SimpleWebAuthn and webauthn-rs tests,
+next to the credential public key:
+
+    {"response": {"authenticatorData": "<base64url>", "clientDataJSON": "<base64url>", "signature": "<base64url>"},
+     "publicKey": "<base64url COSE_Key, or hex SEC1 uncompressed point>"}
+
+The assertion must verify on the host. Tampered variants that every verifier must reject are
+added for each fixture. The stream layout expected by `src/webauthn_fixtures.rs` is:
+
+    count (u32 le)
+    count x [public key x || y, authenticatorData, clientDataJSON, DER signature, expected (1 byte)]
+
+usage: webauthn_inputs.py <fixture json>... <output json>
+"""
+
+import argparse
+import base64
+import hashlib
+import json
+import struct
+
+from cryptography.exceptions import InvalidSignature
+from cryptography.hazmat.primitives import hashes
+from cryptography.hazmat.primitives.asymmetric import ec
+
+
+def item(data: bytes) -> str:
+    # openvm input items are hex strings with a 0x01 prefix for raw bytes
+    return "0x01" + data.hex()
+
+
+def b64url(value: str) -> bytes:
+    return base64.urlsafe_b64decode(value + "=" * (-len(value) % 4))
+
+
+def cbor_item(data: bytes, offset: int):
+    """Decode the CBOR integer, byte string or text string at `offset`, enough for a COSE_Key map."""
+    major, info = data[offset] >> 5, data[offset] & 0x1F
+    offset += 1
+    if info < 24:
+        value = info
+    else:
+        size = {24: 1, 25: 2, 26: 4, 27: 8}[info]
+        value = int.from_bytes(data[offset : offset + size], "big")
+        offset += size
+    if major == 0:
+        return value, offset
+    if major == 1:
+        return -1 - value, offset
+    if major in (2, 3):
+        return data[offset : offset + value], offset + value
+    raise SystemExit(f"unsupported CBOR major type {major} in COSE key")
+
+
+def public_key(value: str) -> bytes:
+    """x || y of an ES256 credential key given as a COSE_Key or an uncompressed SEC1 point."""
+    try:
+        sec1 = bytes.fromhex(value)
+        if len(sec1) == 65 and sec1[0] == 4:
+            return sec1[1:]
+    except ValueError:
+        pass
+    cose = b64url(value)
+    if cose[0] >> 5 != 5:
+        raise SystemExit("COSE key must be a CBOR map")
+    entries, offset = cose[0] & 0x1F, 1
+    key = {}
+    for _ in range(entries):
+        label, offset = cbor_item(cose, offset)
+        key[label], offset = cbor_item(cose, offset)
+    # kty EC2, alg ES256, crv P-256
+    if key.get(1) != 2 or key.get(3) != -7 or key.get(-1) != 1:
+        raise SystemExit("COSE key is not an ES256 P-256 key")
+    return key[-2] + key[-3]
+
+
+def verifies(key: bytes, authenticator_data: bytes, client_data_json: bytes, signature: bytes) -> bool:
+    numbers = ec.EllipticCurvePublicNumbers(int.from_bytes(key[:32], "big"), int.from_bytes(key[32:], "big"), ec.SECP256R1())
+    message = authenticator_data + hashlib.sha256(client_data_json).digest()
+    try:
+        numbers.public_key().verify(signature, message, ec.ECDSA(hashes.SHA256()))
+        return True
+    except InvalidSignature:
+        return False
+
+
+def flipped(data: bytes, index: int) -> bytes:
+    changed = bytearray(data)
+    changed[index] ^= 0x01
+    return bytes(changed)
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__, formatter_class=argparse.RawDescriptionHelpFormatter)
+    parser.add_argument("fixtures", nargs="+", type=argparse.FileType())
+    parser.add_argument("output", type=argparse.FileType("w"))
+    args = parser.parse_args()
+
+    cases = []
+    for fixture in args.fixtures:
+        data = json.load(fixture)
+        response = data.get("response", data)
+        key = public_key(data["publicKey"])
+        authenticator_data = b64url(response["authenticatorData"])
+        client_data_json = b64url(response["clientDataJSON"])
+        signature = b64url(response["signature"])
+        if not verifies(key, authenticator_data, client_data_json, signature):
+            raise SystemExit(f"{fixture.name}: the assertion does not verify")
+
+        cases.append((f"{fixture.name}: captured assertion", key, authenticator_data, client_data_json, signature, True))
+        tampered = [
+            ("user present flag cleared", flipped(authenticator_data, 32), client_data_json, signature),
+            ("sign count changed", flipped(authenticator_data, 36), client_data_json, signature),
+            ("clientDataJSON changed", authenticator_data, client_data_json.replace(b"webauthn.get", b"webauthn.create"), signature),
+            ("last signature byte flipped", authenticator_data, client_data_json, flipped(signature, len(signature) - 1)),
+        ]
+        for description, ad, cd, sig in tampered:
+            assert not verifies(key, ad, cd, sig), description
+            cases.append((f"{fixture.name}: {description}", key, ad, cd, sig, False))
+
+    stream = [item(struct.pack("<I", len(cases)))]
+    for description, key, ad, cd, sig, valid in cases:
+        print(f"{'valid' if valid else 'invalid'}: {description}")
+        stream += [item(key), item(ad), item(cd), item(sig), item(bytes([valid]))]
+    json.dump({"input": stream}, args.output, indent=2)
+
+
+if __name__ == "__main__":
+    main()
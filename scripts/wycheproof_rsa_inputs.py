@@ -0,0 +1,89 @@
+#!/usr/bin/env python3
+"""Convert Wycheproof RSASSA-PKCS1-v1_5 sha256 vectors into an openvm stdin file.
+
+Reads rsa_signature_<bits>_sha256_test.json files of a Wycheproof checkout, e.g.
+testvectors_v1/rsa_signature_{2048,3072,4096}_sha256_test.json, and writes the stream layout
+expected by `src/rsa_wycheproof.rs`:
+
+    count (u32 le)
+    count x [modulus, public exponent, count (u32 le), count x [tcId (u32 le), msg, sig, expected]]
+
+where `expected` is one byte: 0 for "invalid", 1 for "valid" and 2 for "acceptable".
+Moduli and exponents are written big-endian without the leading sign byte of the JSON.
+
+With --flag, only "valid" tests and tests carrying one of the given flags are kept, e.g. the
+padding malformation flags, small enough to check the result in as inputs/rsa_wycheproof.json.
+The flags found in each file are printed with their test counts.
+
+usage: wycheproof_rsa_inputs.py [--flag FLAG]... <rsa_signature json>... <output json>
+"""
+
+import argparse
+import json
+import struct
+from collections import Counter
+
+EXPECTED = {"invalid": 0, "valid": 1, "acceptable": 2}
+
+
+def item(data: bytes) -> str:
+    # openvm input items are hex strings with a 0x01 prefix for raw bytes
+    return "0x01" + data.hex()
+
+
+def u32(n: int) -> str:
+    return item(struct.pack("<I", n))
+
+
+def unsigned(value: str) -> bytes:
+    # Wycheproof writes integers as two's complement hex, so positive values may start with 00
+    return bytes.fromhex(value).lstrip(b"\x00")
+
+
+def public_key(group):
+    # older files keep the key in the group, newer ones under `publicKey`
+    key = group.get("publicKey", group)
+    return unsigned(key.get("modulus", key.get("n"))), unsigned(key.get("publicExponent", key.get("e")))
+
+
+def selected(test, flags) -> bool:
+    return flags is None or test["result"] == "valid" or bool(flags & set(test.get("flags", [])))
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__, formatter_class=argparse.RawDescriptionHelpFormatter)
+    parser.add_argument("--flag", action="append", dest="flags", help="keep valid tests and tests with this flag only, repeatable")
+    parser.add_argument("vectors", nargs="+", type=argparse.FileType())
+    parser.add_argument("output", type=argparse.FileType("w"))
+    args = parser.parse_args()
+
+    flags = None if args.flags is None else set(args.flags)
+    groups = []
+    for vectors in args.vectors:
+        suite = json.load(vectors)
+        for group in suite["testGroups"]:
+            if group.get("sha") != "SHA-256":
+                raise SystemExit(f"{vectors.name}: expected sha256 test groups, found {group.get('sha')}")
+            tests = [test for test in group["tests"] if selected(test, flags)]
+            groups.append({**group, "tests": tests})
+        print(f"{vectors.name}: {suite['numberOfTests']} tests")
+        counts = Counter(flag for group in suite["testGroups"] for test in group["tests"] for flag in test.get("flags", []))
+        for flag, count in sorted(counts.items()):
+            print(f"  {flag}: {count}")
+
+    stream = [u32(len(groups))]
+    for group in groups:
+        modulus, exponent = public_key(group)
+        stream += [item(modulus), item(exponent), u32(len(group["tests"]))]
+        for test in group["tests"]:
+            stream += [
+                u32(test["tcId"]),
+                item(bytes.fromhex(test["msg"])),
+                item(bytes.fromhex(test["sig"])),
+                item(bytes([EXPECTED[test["result"]]])),
+            ]
+    json.dump({"input": stream}, args.output, indent=2)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,497 @@
+use hex_literal::hex;
+use openvm_bigint_guest::{I256, U256};
+
+/// 256-bit operation under test, named after the EVM opcode it backs.
+/// Shift cases take the value as the first operand and the shift amount as the second.
+#[derive(Clone, Copy)]
+enum BigIntOp {
+    Add,
+    Sub,
+    Mul,
+    And,
+    Or,
+    Xor,
+    Shl,
+    Shr,
+    Sar,
+    Lt,
+    Gt,
+    Slt,
+    Sgt,
+    Eq,
+}
+
+/// Vector of test cases for 256-bit integer operations with EVM semantics.
+/// Each test case consists of (operation, big-endian lhs, big-endian rhs, big-endian expected result).
+/// Comparisons return 0 or 1 as the EVM does, shifts by 256 or more give 0, or -1 for a negative sar.
+const BIGINT_TEST_CASES: &[(BigIntOp, [u8; 32], [u8; 32], [u8; 32])] = &[
+    // 1 + 2
+    (
+        BigIntOp::Add,
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000002"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000003"),
+    ),
+    // 2^256 - 1 + 1 wraps to 0
+    (
+        BigIntOp::Add,
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    ),
+    // (2^256 - 1) + (2^256 - 1) wraps
+    (
+        BigIntOp::Add,
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe"),
+    ),
+    // signed overflow: I256::MAX + 1 = I256::MIN
+    (
+        BigIntOp::Add,
+        hex!("7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        hex!("8000000000000000000000000000000000000000000000000000000000000000"),
+    ),
+    // carry across every limb
+    (
+        BigIntOp::Add,
+        hex!("0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef"),
+        hex!("fedcba9876543210fedcba9876543210fedcba9876543210fedcba9876543211"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    ),
+    // 3 - 1
+    (
+        BigIntOp::Sub,
+        hex!("0000000000000000000000000000000000000000000000000000000000000003"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000002"),
+    ),
+    // 0 - 1 wraps to 2^256 - 1
+    (
+        BigIntOp::Sub,
+        hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+    ),
+    // signed overflow: I256::MIN - 1 = I256::MAX
+    (
+        BigIntOp::Sub,
+        hex!("8000000000000000000000000000000000000000000000000000000000000000"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        hex!("7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+    ),
+    // borrow across limbs
+    (
+        BigIntOp::Sub,
+        hex!("0000000000000000000000000000000100000000000000000000000000000000"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        hex!("00000000000000000000000000000000ffffffffffffffffffffffffffffffff"),
+    ),
+    // 6 * 7
+    (
+        BigIntOp::Mul,
+        hex!("0000000000000000000000000000000000000000000000000000000000000006"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000007"),
+        hex!("000000000000000000000000000000000000000000000000000000000000002a"),
+    ),
+    // 2^255 * 2 wraps to 0
+    (
+        BigIntOp::Mul,
+        hex!("8000000000000000000000000000000000000000000000000000000000000000"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000002"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    ),
+    // (-1) * (-1) = 1
+    (
+        BigIntOp::Mul,
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+    ),
+    // signed overflow: I256::MIN * -1 = I256::MIN
+    (
+        BigIntOp::Mul,
+        hex!("8000000000000000000000000000000000000000000000000000000000000000"),
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("8000000000000000000000000000000000000000000000000000000000000000"),
+    ),
+    // (2^128 + 1)(2^128 - 1) = 2^256 - 1 wraps to -1
+    (
+        BigIntOp::Mul,
+        hex!("0000000000000000000000000000000100000000000000000000000000000001"),
+        hex!("00000000000000000000000000000000ffffffffffffffffffffffffffffffff"),
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+    ),
+    // full-width product truncated to 256 bits
+    (
+        BigIntOp::Mul,
+        hex!("0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef"),
+        hex!("fedcba9876543210fedcba9876543210fedcba9876543210fedcba9876543211"),
+        hex!("8d6495a9279987756a0bc31894c0234346b2f08801e6bf11235a1df76f0d5adf"),
+    ),
+    // and
+    (
+        BigIntOp::And,
+        hex!("0000000000000000000000000000000000000000000000000000000000ff00ff"),
+        hex!("00000000000000000000000000000000000000000000000000000000000f0f0f"),
+        hex!("00000000000000000000000000000000000000000000000000000000000f000f"),
+    ),
+    // or
+    (
+        BigIntOp::Or,
+        hex!("0000000000000000000000000000000000000000000000000000000000ff00ff"),
+        hex!("00000000000000000000000000000000000000000000000000000000000f0f0f"),
+        hex!("0000000000000000000000000000000000000000000000000000000000ff0fff"),
+    ),
+    // xor with all ones is not
+    (
+        BigIntOp::Xor,
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("00000000000000000000000000000000000000000000000000000000000f0f0f"),
+        hex!("fffffffffffffffffffffffffffffffffffffffffffffffffffffffffff0f0f0"),
+    ),
+    // 1 << 1
+    (
+        BigIntOp::Shl,
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000002"),
+    ),
+    // 1 << 255
+    (
+        BigIntOp::Shl,
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        hex!("00000000000000000000000000000000000000000000000000000000000000ff"),
+        hex!("8000000000000000000000000000000000000000000000000000000000000000"),
+    ),
+    // shl drops the high bit
+    (
+        BigIntOp::Shl,
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        hex!("fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe"),
+    ),
+    // (2^256 - 1) << 255
+    (
+        BigIntOp::Shl,
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("00000000000000000000000000000000000000000000000000000000000000ff"),
+        hex!("8000000000000000000000000000000000000000000000000000000000000000"),
+    ),
+    // shl across limbs
+    (
+        BigIntOp::Shl,
+        hex!("0000000000000000000000000000000000000000000000000123456789abcdef"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000064"),
+        hex!("000000000000000000000000123456789abcdef0000000000000000000000000"),
+    ),
+    // 1 << 256 = 0
+    (
+        BigIntOp::Shl,
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000100"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    ),
+    // 1 << (2^255 + 1) = 0, the low byte alone would shift by 1
+    (
+        BigIntOp::Shl,
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        hex!("8000000000000000000000000000000000000000000000000000000000000001"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    ),
+    // 2^255 >> 1 is logical
+    (
+        BigIntOp::Shr,
+        hex!("8000000000000000000000000000000000000000000000000000000000000000"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        hex!("4000000000000000000000000000000000000000000000000000000000000000"),
+    ),
+    // 2^255 >> 255
+    (
+        BigIntOp::Shr,
+        hex!("8000000000000000000000000000000000000000000000000000000000000000"),
+        hex!("00000000000000000000000000000000000000000000000000000000000000ff"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+    ),
+    // shr by 0
+    (
+        BigIntOp::Shr,
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+    ),
+    // shr across limbs
+    (
+        BigIntOp::Shr,
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("00000000000000000000000000000000000000000000000000000000000000c8"),
+        hex!("00000000000000000000000000000000000000000000000000ffffffffffffff"),
+    ),
+    // (2^256 - 1) >> 256 = 0
+    (
+        BigIntOp::Shr,
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000100"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    ),
+    // shr by 2^64 + 1
+    (
+        BigIntOp::Shr,
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("0000000000000000000000000000000000000000000000010000000000000001"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    ),
+    // I256::MIN >> 1 keeps the sign
+    (
+        BigIntOp::Sar,
+        hex!("8000000000000000000000000000000000000000000000000000000000000000"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        hex!("c000000000000000000000000000000000000000000000000000000000000000"),
+    ),
+    // I256::MIN >> 255 = -1
+    (
+        BigIntOp::Sar,
+        hex!("8000000000000000000000000000000000000000000000000000000000000000"),
+        hex!("00000000000000000000000000000000000000000000000000000000000000ff"),
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+    ),
+    // -1 >> 255 = -1
+    (
+        BigIntOp::Sar,
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("00000000000000000000000000000000000000000000000000000000000000ff"),
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+    ),
+    // positive sar is logical
+    (
+        BigIntOp::Sar,
+        hex!("4000000000000000000000000000000000000000000000000000000000000000"),
+        hex!("00000000000000000000000000000000000000000000000000000000000000fe"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+    ),
+    // -8 >> 1 = -4
+    (
+        BigIntOp::Sar,
+        hex!("fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff8"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        hex!("fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc"),
+    ),
+    // I256::MIN >> 256 = -1
+    (
+        BigIntOp::Sar,
+        hex!("8000000000000000000000000000000000000000000000000000000000000000"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000100"),
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+    ),
+    // -1 >> (2^255 + 1) = -1
+    (
+        BigIntOp::Sar,
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("8000000000000000000000000000000000000000000000000000000000000001"),
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+    ),
+    // positive sar by 256 = 0
+    (
+        BigIntOp::Sar,
+        hex!("7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000100"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    ),
+    // 9 < 10
+    (
+        BigIntOp::Lt,
+        hex!("0000000000000000000000000000000000000000000000000000000000000009"),
+        hex!("000000000000000000000000000000000000000000000000000000000000000a"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+    ),
+    // unsigned 2^256 - 1 < 0 is false
+    (
+        BigIntOp::Lt,
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    ),
+    // lt decided by the low limb
+    (
+        BigIntOp::Lt,
+        hex!("0000000000000000000000000000000100000000000000000000000000000000"),
+        hex!("0000000000000000000000000000000100000000000000000000000000000001"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+    ),
+    // 10 > 9
+    (
+        BigIntOp::Gt,
+        hex!("000000000000000000000000000000000000000000000000000000000000000a"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000009"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+    ),
+    // unsigned 2^256 - 1 > 0
+    (
+        BigIntOp::Gt,
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+    ),
+    // signed -1 < 0
+    (
+        BigIntOp::Slt,
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+    ),
+    // I256::MIN < I256::MAX
+    (
+        BigIntOp::Slt,
+        hex!("8000000000000000000000000000000000000000000000000000000000000000"),
+        hex!("7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+    ),
+    // 0 < -1 is false
+    (
+        BigIntOp::Slt,
+        hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    ),
+    // signed 0 > -1
+    (
+        BigIntOp::Sgt,
+        hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+    ),
+    // I256::MAX > I256::MIN
+    (
+        BigIntOp::Sgt,
+        hex!("7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("8000000000000000000000000000000000000000000000000000000000000000"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+    ),
+    // -4 > -5
+    (
+        BigIntOp::Sgt,
+        hex!("fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc"),
+        hex!("fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffb"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+    ),
+    // equal
+    (
+        BigIntOp::Eq,
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+    ),
+    // differ in the lowest bit
+    (
+        BigIntOp::Eq,
+        hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        hex!("fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    ),
+    // differ in the highest bit
+    (
+        BigIntOp::Eq,
+        hex!("8000000000000000000000000000000000000000000000000000000000000000"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    ),
+];
+
+/// Convert a big-endian EVM word into a U256
+fn u256(be_bytes: &[u8; 32]) -> U256 {
+    let mut le_bytes = *be_bytes;
+    le_bytes.reverse();
+    U256::from_le_bytes(le_bytes)
+}
+
+/// Convert a big-endian EVM word into a two's complement I256
+fn i256(be_bytes: &[u8; 32]) -> I256 {
+    let mut le_bytes = *be_bytes;
+    le_bytes.reverse();
+    I256::from_le_bytes(le_bytes)
+}
+
+/// Convert a U256 back into a big-endian EVM word
+fn u256_to_be_bytes(value: &U256) -> [u8; 32] {
+    let mut be_bytes = *value.as_le_bytes();
+    be_bytes.reverse();
+    be_bytes
+}
+
+/// Convert an I256 back into a big-endian EVM word
+fn i256_to_be_bytes(value: &I256) -> [u8; 32] {
+    let mut be_bytes = *value.as_le_bytes();
+    be_bytes.reverse();
+    be_bytes
+}
+
+/// Whether a big-endian EVM word is a shift amount below 256
+fn shift_below_256(be_bytes: &[u8; 32]) -> bool {
+    be_bytes[..31].iter().all(|&byte| byte == 0)
+}
+
+/// Convert a comparison result into the EVM word 0 or 1
+fn bool_to_be_bytes(value: bool) -> [u8; 32] {
+    u256_to_be_bytes(&U256::from_u8(value as u8))
+}
+
+/// Run all 256-bit integer test cases
+pub fn run_bigint_tests() {
+    for (op, lhs, rhs, expected) in BIGINT_TEST_CASES {
+        let (a, b) = (u256(lhs), u256(rhs));
+        let (signed_a, signed_b) = (i256(lhs), i256(rhs));
+
+        let result = match op {
+            BigIntOp::Add => {
+                // two's complement addition is identical for signed and unsigned operands
+                assert_eq!(i256_to_be_bytes(&(&signed_a + &signed_b)), *expected);
+                u256_to_be_bytes(&(&a + &b))
+            }
+            BigIntOp::Sub => {
+                assert_eq!(i256_to_be_bytes(&(&signed_a - &signed_b)), *expected);
+                u256_to_be_bytes(&(&a - &b))
+            }
+            BigIntOp::Mul => {
+                assert_eq!(i256_to_be_bytes(&(&signed_a * &signed_b)), *expected);
+                u256_to_be_bytes(&(&a * &b))
+            }
+            BigIntOp::And => u256_to_be_bytes(&(&a & &b)),
+            BigIntOp::Or => u256_to_be_bytes(&(&a | &b)),
+            BigIntOp::Xor => u256_to_be_bytes(&(&a ^ &b)),
+            // the U256 and I256 shifts only use the low bits of the amount, the EVM saturates
+            BigIntOp::Shl if !shift_below_256(rhs) => u256_to_be_bytes(&U256::ZERO),
+            BigIntOp::Shl => u256_to_be_bytes(&(&a << &b)),
+            BigIntOp::Shr if !shift_below_256(rhs) => u256_to_be_bytes(&U256::ZERO),
+            BigIntOp::Shr => u256_to_be_bytes(&(&a >> &b)),
+            BigIntOp::Sar if !shift_below_256(rhs) => {
+                let sign_fill = if signed_a < I256::from_i32(0) { -1 } else { 0 };
+                i256_to_be_bytes(&I256::from_i32(sign_fill))
+            }
+            BigIntOp::Sar => i256_to_be_bytes(&(&signed_a >> &signed_b)),
+            BigIntOp::Lt => bool_to_be_bytes(a < b),
+            BigIntOp::Gt => bool_to_be_bytes(a > b),
+            BigIntOp::Slt => bool_to_be_bytes(signed_a < signed_b),
+            BigIntOp::Sgt => bool_to_be_bytes(signed_a > signed_b),
+            BigIntOp::Eq => bool_to_be_bytes(a == b),
+        };
+        assert_eq!(result, *expected);
+    }
+
+    // constants match the EVM boundary words
+    assert_eq!(u256_to_be_bytes(&U256::MAX), [0xff; 32]);
+    assert_eq!(u256_to_be_bytes(&U256::ZERO), [0; 32]);
+    assert_eq!(
+        i256_to_be_bytes(&I256::MIN),
+        hex!("8000000000000000000000000000000000000000000000000000000000000000")
+    );
+    assert_eq!(
+        i256_to_be_bytes(&I256::MAX),
+        hex!("7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff")
+    );
+    assert_eq!(i256_to_be_bytes(&I256::from_i32(-1)), [0xff; 32]);
+    assert_eq!(
+        u256_to_be_bytes(&U256::from_u64(u64::MAX)),
+        hex!("000000000000000000000000000000000000000000000000ffffffffffffffff")
+    );
+}
@@ -1,244 +1,306 @@
 use hex_literal::hex;
+#[cfg(feature = "pairing-scaling")]
+use openvm_algebra_guest::IntMod;
+#[cfg(feature = "pairing-scaling")]
+use openvm_pairing_guest::bn254::Scalar;
 use revm_precompile::{
     bn128::{run_add, run_mul, run_pair},
-    PrecompileError, PrecompileErrors,
+    PrecompileError, PrecompileErrors, PrecompileResult,
 };
 
+#[cfg(feature = "pairing-scaling")]
+use crate::cycle_tracker;
+
+/// Type alias for test case input
+type TestCaseInput = &'static [u8];
+/// Type alias for test case expected output
+type TestCaseOutput = Result<&'static [u8], PrecompileErrors>;
+/// Type alias for a bn128 precompile test case
+type Bn128TestCase = (TestCaseInput, TestCaseOutput);
+
 /// Vector of test cases for ecAdd precompile.
-/// Each test case consists of (input_bytes, expected_output_bytes).
-const ECADD_CASES: &[(&[u8], [u8; 64])] = &[
+/// Each test case consists of (input_bytes, expected_output_bytes or expected_error).
+pub(crate) const ECADD_CASES: &[Bn128TestCase] = &[
     // add 1
     (
         &hex!("18b18acfb4c2c30276db5411368e7185b311dd124691610c5d3b74034e093dc9063c909c4720840cb5134cb9f59fa749755796819658d32efc0d288198f3726607c2b7f58a84bd6145f00c9c2bc0bb1a187f20ff2c92963a88019e7c6a014eed06614e20c147e940f2d70da3f74c9a17df361706a4485c742bd6788478fa17d7"),
-        hex!("2243525c5efd4b9c3d3c45ac0ca3fe4dd85e830a4ce6b65fa1eeaee202839703301d1d33be6da8e509df21cc35964723180eed7532537db9ae5e7d48f195c915"),
+        Ok(&hex!("2243525c5efd4b9c3d3c45ac0ca3fe4dd85e830a4ce6b65fa1eeaee202839703301d1d33be6da8e509df21cc35964723180eed7532537db9ae5e7d48f195c915")),
     ),
     // ethereum/tests/Addition on elliptic curve alt_bn128
     (
         &hex!("0f25929bcb43d5a57391564615c9e70a992b10eafa4db109709649cf48c50dd216da2f5cb6be7a0aa72c440c53c9bbdfec6c36c7d515536431b3a865468acbba1de49a4b0233273bba8146af82042d004f2085ec982397db0d97da17204cc2860217327ffc463919bef80cc166d09c6172639d8589799928761bcd9f22c903d4"),
-        hex!("1f4d1d80177b1377743d1901f70d7389be7f7a35a35bfd234a8aaee615b88c49018683193ae021a2f8920fed186cde5d9b1365116865281ccf884c1f28b1df8f"),
+        Ok(&hex!("1f4d1d80177b1377743d1901f70d7389be7f7a35a35bfd234a8aaee615b88c49018683193ae021a2f8920fed186cde5d9b1365116865281ccf884c1f28b1df8f")),
     ),
     // zero sum
     (
         &hex!("0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
-        hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+        Ok(&hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000")),
     ),
     // no input
     (
         b"",
-        hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000")
+        Ok(&hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256Add.json#chfast1
     (
         &hex!("18b18acfb4c2c30276db5411368e7185b311dd124691610c5d3b74034e093dc9063c909c4720840cb5134cb9f59fa749755796819658d32efc0d288198f3726607c2b7f58a84bd6145f00c9c2bc0bb1a187f20ff2c92963a88019e7c6a014eed06614e20c147e940f2d70da3f74c9a17df361706a4485c742bd6788478fa17d7"),
-        hex!("2243525c5efd4b9c3d3c45ac0ca3fe4dd85e830a4ce6b65fa1eeaee202839703301d1d33be6da8e509df21cc35964723180eed7532537db9ae5e7d48f195c915"),
+        Ok(&hex!("2243525c5efd4b9c3d3c45ac0ca3fe4dd85e830a4ce6b65fa1eeaee202839703301d1d33be6da8e509df21cc35964723180eed7532537db9ae5e7d48f195c915")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256Add.json#chfast2
     (
         &hex!("2243525c5efd4b9c3d3c45ac0ca3fe4dd85e830a4ce6b65fa1eeaee202839703301d1d33be6da8e509df21cc35964723180eed7532537db9ae5e7d48f195c91518b18acfb4c2c30276db5411368e7185b311dd124691610c5d3b74034e093dc9063c909c4720840cb5134cb9f59fa749755796819658d32efc0d288198f37266"),
-        hex!("2bd3e6d0f3b142924f5ca7b49ce5b9d54c4703d7ae5648e61d02268b1a0a9fb721611ce0a6af85915e2f1d70300909ce2e49dfad4a4619c8390cae66cefdb204"),
+        Ok(&hex!("2bd3e6d0f3b142924f5ca7b49ce5b9d54c4703d7ae5648e61d02268b1a0a9fb721611ce0a6af85915e2f1d70300909ce2e49dfad4a4619c8390cae66cefdb204")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256Add.json#cdetrio1
     (
         &hex!("0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
-        hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+        Ok(&hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256Add.json#cdetrio2
     (
         &hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
-        hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+        Ok(&hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256Add.json#cdetrio3
     (
         &hex!("0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
-        hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+        Ok(&hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256Add.json#cdetrio4
     (
         &hex!(""),
-        hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+        Ok(&hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256Add.json#cdetrio5
     (
         &hex!("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
-        hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+        Ok(&hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256Add.json#cdetrio6
     (
         &hex!("0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002"),
-        hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002"),
+        Ok(&hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256Add.json#cdetrio7
     (
         &hex!("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
-        hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002"),
+        Ok(&hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256Add.json#cdetrio8
     (
         &hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002"),
-        hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002"),
+        Ok(&hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256Add.json#cdetrio9
     (
         &hex!("0000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
-        hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002"),
+        Ok(&hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256Add.json#cdetrio10
     (
         &hex!("000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
-        hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002"),
+        Ok(&hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256Add.json#cdetrio11
     (
         &hex!("0000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002"),
-        hex!("030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd315ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4"),
+        Ok(&hex!("030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd315ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256Add.json#cdetrio12
     (
         &hex!("000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
-        hex!("030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd315ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4"),
+        Ok(&hex!("030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd315ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256Add.json#cdetrio13
     (
         &hex!("17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa901e0559bacb160664764a357af8a9fe70baa9258e0b959273ffc5718c6d4cc7c039730ea8dff1254c0fee9c0ea777d29a9c710b7e616683f194f18c43b43b869073a5ffcc6fc7a28c30723d6e58ce577356982d65b833a5a5c15bf9024b43d98"),
-        hex!("15bf2bb17880144b5d1cd2b1f46eff9d617bffd1ca57c37fb5a49bd84e53cf66049c797f9ce0d17083deb32b5e36f2ea2a212ee036598dd7624c168993d1355f"),
+        Ok(&hex!("15bf2bb17880144b5d1cd2b1f46eff9d617bffd1ca57c37fb5a49bd84e53cf66049c797f9ce0d17083deb32b5e36f2ea2a212ee036598dd7624c168993d1355f")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256Add.json#cdetrio14
     (
         &hex!("17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa901e0559bacb160664764a357af8a9fe70baa9258e0b959273ffc5718c6d4cc7c17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa92e83f8d734803fc370eba25ed1f6b8768bd6d83887b87165fc2434fe11a830cb00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
-        hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+        Ok(&hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000")),
+    ),
+    // first point not on curve
+    (
+        &hex!("0000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000300000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002"),
+        Err(PrecompileErrors::Error(PrecompileError::Bn128AffineGFailedToCreate)),
+    ),
+    // second point not on curve
+    (
+        &hex!("0000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000003"),
+        Err(PrecompileErrors::Error(PrecompileError::Bn128AffineGFailedToCreate)),
+    ),
+    // (0, 1) is not the point at infinity
+    (
+        &hex!("0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002"),
+        Err(PrecompileErrors::Error(PrecompileError::Bn128AffineGFailedToCreate)),
+    ),
+    // first x coordinate equal to p
+    (
+        &hex!("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002"),
+        Err(PrecompileErrors::Error(PrecompileError::Bn128FieldPointNotAMember)),
+    ),
+    // second y coordinate equal to y + p
+    (
+        &hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000130644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd49"),
+        Err(PrecompileErrors::Error(PrecompileError::Bn128FieldPointNotAMember)),
+    ),
+    // x coordinate 2^256 - 1
+    (
+        &hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002"),
+        Err(PrecompileErrors::Error(PrecompileError::Bn128FieldPointNotAMember)),
+    ),
+    // point not on curve, second point omitted
+    (
+        &hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000003"),
+        Err(PrecompileErrors::Error(PrecompileError::Bn128AffineGFailedToCreate)),
     ),
 ];
 
 /// Vector of test cases for ecMul precompile.
-/// Each test case consists of (input_bytes, expected_output_bytes).
-const ECMUL_CASES: &[(&[u8], [u8; 64])] = &[
+/// Each test case consists of (input_bytes, expected_output_bytes or expected_error).
+pub(crate) const ECMUL_CASES: &[Bn128TestCase] = &[
     // mul
     (
         &hex!("2bd3e6d0f3b142924f5ca7b49ce5b9d54c4703d7ae5648e61d02268b1a0a9fb721611ce0a6af85915e2f1d70300909ce2e49dfad4a4619c8390cae66cefdb20400000000000000000000000000000000000000000000000011138ce750fa15c2"),
-        hex!("070a8d6a982153cae4be29d434e8faef8a47b274a053f5a4ee2a6c9c13c31e5c031b8ce914eba3a9ffb989f9cdd5b0f01943074bf4f0f315690ec3cec6981afc"),
+        Ok(&hex!("070a8d6a982153cae4be29d434e8faef8a47b274a053f5a4ee2a6c9c13c31e5c031b8ce914eba3a9ffb989f9cdd5b0f01943074bf4f0f315690ec3cec6981afc")),
     ),
     // ethereum/tests/Scalar multiplication on elliptic curve alt_bn128
     (
         &hex!("0f25929bcb43d5a57391564615c9e70a992b10eafa4db109709649cf48c50dd216da2f5cb6be7a0aa72c440c53c9bbdfec6c36c7d515536431b3a865468acbba0000000000000000000000000000000000000000000000000000000000000003"),
-        hex!("1f4d1d80177b1377743d1901f70d7389be7f7a35a35bfd234a8aaee615b88c49018683193ae021a2f8920fed186cde5d9b1365116865281ccf884c1f28b1df8f"),
+        Ok(&hex!("1f4d1d80177b1377743d1901f70d7389be7f7a35a35bfd234a8aaee615b88c49018683193ae021a2f8920fed186cde5d9b1365116865281ccf884c1f28b1df8f")),
     ),
     // zero multiplication
     (
         &hex!("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000"),
-        hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+        Ok(&hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000")),
     ),
     // no input
     (
         b"",
-        hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000")
+        Ok(&hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256ScalarMul.json#chfast1
     (
         &hex!("2bd3e6d0f3b142924f5ca7b49ce5b9d54c4703d7ae5648e61d02268b1a0a9fb721611ce0a6af85915e2f1d70300909ce2e49dfad4a4619c8390cae66cefdb20400000000000000000000000000000000000000000000000011138ce750fa15c2"),
-        hex!("070a8d6a982153cae4be29d434e8faef8a47b274a053f5a4ee2a6c9c13c31e5c031b8ce914eba3a9ffb989f9cdd5b0f01943074bf4f0f315690ec3cec6981afc"),
+        Ok(&hex!("070a8d6a982153cae4be29d434e8faef8a47b274a053f5a4ee2a6c9c13c31e5c031b8ce914eba3a9ffb989f9cdd5b0f01943074bf4f0f315690ec3cec6981afc")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256ScalarMul.json#chfast2
     (
         &hex!("070a8d6a982153cae4be29d434e8faef8a47b274a053f5a4ee2a6c9c13c31e5c031b8ce914eba3a9ffb989f9cdd5b0f01943074bf4f0f315690ec3cec6981afc30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd46"),
-        hex!("025a6f4181d2b4ea8b724290ffb40156eb0adb514c688556eb79cdea0752c2bb2eff3f31dea215f1eb86023a133a996eb6300b44da664d64251d05381bb8a02e"),
+        Ok(&hex!("025a6f4181d2b4ea8b724290ffb40156eb0adb514c688556eb79cdea0752c2bb2eff3f31dea215f1eb86023a133a996eb6300b44da664d64251d05381bb8a02e")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256ScalarMul.json#chfast3
     (
         &hex!("025a6f4181d2b4ea8b724290ffb40156eb0adb514c688556eb79cdea0752c2bb2eff3f31dea215f1eb86023a133a996eb6300b44da664d64251d05381bb8a02e183227397098d014dc2822db40c0ac2ecbc0b548b438e5469e10460b6c3e7ea3"),
-        hex!("14789d0d4a730b354403b5fac948113739e276c23e0258d8596ee72f9cd9d3230af18a63153e0ec25ff9f2951dd3fa90ed0197bfef6e2a1a62b5095b9d2b4a27"),
+        Ok(&hex!("14789d0d4a730b354403b5fac948113739e276c23e0258d8596ee72f9cd9d3230af18a63153e0ec25ff9f2951dd3fa90ed0197bfef6e2a1a62b5095b9d2b4a27")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256ScalarMul.json#cdetrio1
     (
         &hex!("1a87b0584ce92f4593d161480614f2989035225609f08058ccfa3d0f940febe31a2f3c951f6dadcc7ee9007dff81504b0fcd6d7cf59996efdc33d92bf7f9f8f6ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
-        hex!("2cde5879ba6f13c0b5aa4ef627f159a3347df9722efce88a9afbb20b763b4c411aa7e43076f6aee272755a7f9b84832e71559ba0d2e0b17d5f9f01755e5b0d11"),
+        Ok(&hex!("2cde5879ba6f13c0b5aa4ef627f159a3347df9722efce88a9afbb20b763b4c411aa7e43076f6aee272755a7f9b84832e71559ba0d2e0b17d5f9f01755e5b0d11")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256ScalarMul.json#cdetrio2
     (
         &hex!("1a87b0584ce92f4593d161480614f2989035225609f08058ccfa3d0f940febe31a2f3c951f6dadcc7ee9007dff81504b0fcd6d7cf59996efdc33d92bf7f9f8f630644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000"),
-        hex!("1a87b0584ce92f4593d161480614f2989035225609f08058ccfa3d0f940febe3163511ddc1c3f25d396745388200081287b3fd1472d8339d5fecb2eae0830451"),
+        Ok(&hex!("1a87b0584ce92f4593d161480614f2989035225609f08058ccfa3d0f940febe3163511ddc1c3f25d396745388200081287b3fd1472d8339d5fecb2eae0830451")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256ScalarMul.json#cdetrio3
     (
         &hex!("1a87b0584ce92f4593d161480614f2989035225609f08058ccfa3d0f940febe31a2f3c951f6dadcc7ee9007dff81504b0fcd6d7cf59996efdc33d92bf7f9f8f60000000000000000000000000000000100000000000000000000000000000000"),
-        hex!("1051acb0700ec6d42a88215852d582efbaef31529b6fcbc3277b5c1b300f5cf0135b2394bb45ab04b8bd7611bd2dfe1de6a4e6e2ccea1ea1955f577cd66af85b"),
+        Ok(&hex!("1051acb0700ec6d42a88215852d582efbaef31529b6fcbc3277b5c1b300f5cf0135b2394bb45ab04b8bd7611bd2dfe1de6a4e6e2ccea1ea1955f577cd66af85b")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256ScalarMul.json#cdetrio4
     (
         &hex!("1a87b0584ce92f4593d161480614f2989035225609f08058ccfa3d0f940febe31a2f3c951f6dadcc7ee9007dff81504b0fcd6d7cf59996efdc33d92bf7f9f8f60000000000000000000000000000000000000000000000000000000000000009"),
-        hex!("1dbad7d39dbc56379f78fac1bca147dc8e66de1b9d183c7b167351bfe0aeab742cd757d51289cd8dbd0acf9e673ad67d0f0a89f912af47ed1be53664f5692575"),
+        Ok(&hex!("1dbad7d39dbc56379f78fac1bca147dc8e66de1b9d183c7b167351bfe0aeab742cd757d51289cd8dbd0acf9e673ad67d0f0a89f912af47ed1be53664f5692575")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256ScalarMul.json#cdetrio5
     (
         &hex!("1a87b0584ce92f4593d161480614f2989035225609f08058ccfa3d0f940febe31a2f3c951f6dadcc7ee9007dff81504b0fcd6d7cf59996efdc33d92bf7f9f8f60000000000000000000000000000000000000000000000000000000000000001"),
-        hex!("1a87b0584ce92f4593d161480614f2989035225609f08058ccfa3d0f940febe31a2f3c951f6dadcc7ee9007dff81504b0fcd6d7cf59996efdc33d92bf7f9f8f6"),
+        Ok(&hex!("1a87b0584ce92f4593d161480614f2989035225609f08058ccfa3d0f940febe31a2f3c951f6dadcc7ee9007dff81504b0fcd6d7cf59996efdc33d92bf7f9f8f6")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256ScalarMul.json#cdetrio6
     (
         &hex!("17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa901e0559bacb160664764a357af8a9fe70baa9258e0b959273ffc5718c6d4cc7cffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
-        hex!("29e587aadd7c06722aabba753017c093f70ba7eb1f1c0104ec0564e7e3e21f6022b1143f6a41008e7755c71c3d00b6b915d386de21783ef590486d8afa8453b1"),
+        Ok(&hex!("29e587aadd7c06722aabba753017c093f70ba7eb1f1c0104ec0564e7e3e21f6022b1143f6a41008e7755c71c3d00b6b915d386de21783ef590486d8afa8453b1")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256ScalarMul.json#cdetrio7
     (
         &hex!("17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa901e0559bacb160664764a357af8a9fe70baa9258e0b959273ffc5718c6d4cc7c30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000"),
-        hex!("17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa92e83f8d734803fc370eba25ed1f6b8768bd6d83887b87165fc2434fe11a830cb"),
+        Ok(&hex!("17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa92e83f8d734803fc370eba25ed1f6b8768bd6d83887b87165fc2434fe11a830cb")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256ScalarMul.json#cdetrio8
     (
         &hex!("17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa901e0559bacb160664764a357af8a9fe70baa9258e0b959273ffc5718c6d4cc7c0000000000000000000000000000000100000000000000000000000000000000"),
-        hex!("221a3577763877920d0d14a91cd59b9479f83b87a653bb41f82a3f6f120cea7c2752c7f64cdd7f0e494bff7b60419f242210f2026ed2ec70f89f78a4c56a1f15"),
+        Ok(&hex!("221a3577763877920d0d14a91cd59b9479f83b87a653bb41f82a3f6f120cea7c2752c7f64cdd7f0e494bff7b60419f242210f2026ed2ec70f89f78a4c56a1f15")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256ScalarMul.json#cdetrio9
     (
         &hex!("17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa901e0559bacb160664764a357af8a9fe70baa9258e0b959273ffc5718c6d4cc7c0000000000000000000000000000000000000000000000000000000000000009"),
-        hex!("228e687a379ba154554040f8821f4e41ee2be287c201aa9c3bc02c9dd12f1e691e0fd6ee672d04cfd924ed8fdc7ba5f2d06c53c1edc30f65f2af5a5b97f0a76a"),
+        Ok(&hex!("228e687a379ba154554040f8821f4e41ee2be287c201aa9c3bc02c9dd12f1e691e0fd6ee672d04cfd924ed8fdc7ba5f2d06c53c1edc30f65f2af5a5b97f0a76a")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256ScalarMul.json#cdetrio10
     (
         &hex!("17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa901e0559bacb160664764a357af8a9fe70baa9258e0b959273ffc5718c6d4cc7c0000000000000000000000000000000000000000000000000000000000000001"),
-        hex!("17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa901e0559bacb160664764a357af8a9fe70baa9258e0b959273ffc5718c6d4cc7c"),
+        Ok(&hex!("17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa901e0559bacb160664764a357af8a9fe70baa9258e0b959273ffc5718c6d4cc7c")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256ScalarMul.json#cdetrio11
     (
         &hex!("039730ea8dff1254c0fee9c0ea777d29a9c710b7e616683f194f18c43b43b869073a5ffcc6fc7a28c30723d6e58ce577356982d65b833a5a5c15bf9024b43d98ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
-        hex!("00a1a234d08efaa2616607e31eca1980128b00b415c845ff25bba3afcb81dc00242077290ed33906aeb8e42fd98c41bcb9057ba03421af3f2d08cfc441186024"),
+        Ok(&hex!("00a1a234d08efaa2616607e31eca1980128b00b415c845ff25bba3afcb81dc00242077290ed33906aeb8e42fd98c41bcb9057ba03421af3f2d08cfc441186024")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256ScalarMul.json#cdetrio12
     (
         &hex!("039730ea8dff1254c0fee9c0ea777d29a9c710b7e616683f194f18c43b43b869073a5ffcc6fc7a28c30723d6e58ce577356982d65b833a5a5c15bf9024b43d9830644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000"),
-        hex!("039730ea8dff1254c0fee9c0ea777d29a9c710b7e616683f194f18c43b43b8692929ee761a352600f54921df9bf472e66217e7bb0cee9032e00acc86b3c8bfaf"),
+        Ok(&hex!("039730ea8dff1254c0fee9c0ea777d29a9c710b7e616683f194f18c43b43b8692929ee761a352600f54921df9bf472e66217e7bb0cee9032e00acc86b3c8bfaf")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256ScalarMul.json#cdetrio13
     (
         &hex!("039730ea8dff1254c0fee9c0ea777d29a9c710b7e616683f194f18c43b43b869073a5ffcc6fc7a28c30723d6e58ce577356982d65b833a5a5c15bf9024b43d980000000000000000000000000000000100000000000000000000000000000000"),
-        hex!("1071b63011e8c222c5a771dfa03c2e11aac9666dd097f2c620852c3951a4376a2f46fe2f73e1cf310a168d56baa5575a8319389d7bfa6b29ee2d908305791434"),
+        Ok(&hex!("1071b63011e8c222c5a771dfa03c2e11aac9666dd097f2c620852c3951a4376a2f46fe2f73e1cf310a168d56baa5575a8319389d7bfa6b29ee2d908305791434")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256ScalarMul.json#cdetrio14
     (
         &hex!("039730ea8dff1254c0fee9c0ea777d29a9c710b7e616683f194f18c43b43b869073a5ffcc6fc7a28c30723d6e58ce577356982d65b833a5a5c15bf9024b43d980000000000000000000000000000000000000000000000000000000000000009"),
-        hex!("19f75b9dd68c080a688774a6213f131e3052bd353a304a189d7a2ee367e3c2582612f545fb9fc89fde80fd81c68fc7dcb27fea5fc124eeda69433cf5c46d2d7f"),
+        Ok(&hex!("19f75b9dd68c080a688774a6213f131e3052bd353a304a189d7a2ee367e3c2582612f545fb9fc89fde80fd81c68fc7dcb27fea5fc124eeda69433cf5c46d2d7f")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256ScalarMul.json#cdetrio15
     (
         &hex!("039730ea8dff1254c0fee9c0ea777d29a9c710b7e616683f194f18c43b43b869073a5ffcc6fc7a28c30723d6e58ce577356982d65b833a5a5c15bf9024b43d980000000000000000000000000000000000000000000000000000000000000001"),
-        hex!("039730ea8dff1254c0fee9c0ea777d29a9c710b7e616683f194f18c43b43b869073a5ffcc6fc7a28c30723d6e58ce577356982d65b833a5a5c15bf9024b43d98"),
+        Ok(&hex!("039730ea8dff1254c0fee9c0ea777d29a9c710b7e616683f194f18c43b43b869073a5ffcc6fc7a28c30723d6e58ce577356982d65b833a5a5c15bf9024b43d98")),
     ),
     // go-ethereum/core/vm/testdata/precompiles/bn256ScalarMul.json#zeroScalar
     (
         &hex!("039730ea8dff1254c0fee9c0ea777d29a9c710b7e616683f194f18c43b43b869073a5ffcc6fc7a28c30723d6e58ce577356982d65b833a5a5c15bf9024b43d980000000000000000000000000000000000000000000000000000000000000000"),
-        hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+        Ok(&hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000")),
+    ),
+    // point not on curve
+    (
+        &hex!("000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000030000000000000000000000000000000000000000000000000000000000000002"),
+        Err(PrecompileErrors::Error(PrecompileError::Bn128AffineGFailedToCreate)),
+    ),
+    // point not on curve, zero scalar
+    (
+        &hex!("000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000030000000000000000000000000000000000000000000000000000000000000000"),
+        Err(PrecompileErrors::Error(PrecompileError::Bn128AffineGFailedToCreate)),
+    ),
+    // x coordinate equal to p
+    (
+        &hex!("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd4700000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000001"),
+        Err(PrecompileErrors::Error(PrecompileError::Bn128FieldPointNotAMember)),
+    ),
+    // y coordinate equal to y + p
+    (
+        &hex!("000000000000000000000000000000000000000000000000000000000000000130644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd490000000000000000000000000000000000000000000000000000000000000001"),
+        Err(PrecompileErrors::Error(PrecompileError::Bn128FieldPointNotAMember)),
     ),
 ];
 
-/// Type alias for test case input
-type TestCaseInput = &'static [u8];
-/// Type alias for test case expected output
-type TestCaseOutput = Result<&'static [u8], PrecompileErrors>;
-/// Type alias for a pairing test case
-type PairingTestCase = (TestCaseInput, TestCaseOutput);
-
 /// Vector of test cases for ecPairing precompile.
 /// Each test case consists of (input_bytes, expected_output_bytes or expected_error).
-const ECPAIRING_CASES: &[PairingTestCase] = &[
+pub(crate) const ECPAIRING_CASES: &[Bn128TestCase] = &[
     // pair
     (
         &hex!("1c76476f4def4bb94541d57ebba1193381ffa7aa76ada664dd31c16024c43f593034dd2920f673e204fee2811c678745fc819b55d3e9d294e45c9b03a76aef41209dd15ebff5d46c4bd888e51a93cf99a7329636c63514396b4a452003a35bf704bf11ca01483bfa8b34b43561848d28905960114c8ac04049af4b6315a416782bb8324af6cfc93537a2ad1a445cfd0ca2a71acd7ac41fadbf933c2a51be344d120a2a4cf30c1bf9845f20c6fe39e07ea2cce61f0c9bb048165fe5e4de877550111e129f1cf1097710d41c4ac70fcdfa5ba2023c6ff1cbeac322de49d1b6df7c2032c61a830e3c17286de9462bf242fca2883585b93870a73853face6a6bf411198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c21800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"),
@@ -328,43 +390,164 @@ const ECPAIRING_CASES: &[PairingTestCase] = &[
     (
         &hex!("06a7b64af8f414bcbeef455b1da5208c9b592b83ee6599824caa6d2ee9141a76277d002f54436e7da803601aec9cf8740ce99198c18a74286d979cbc9695cd8b1014772f57bb9742735191cd5dcfe4ebbc04156b6878a0a7c9824f32ffb66e8506064e784db10e9051e52826e192715e8d7e478cb09a5e0012defa0694fbc7f5021e2335f3354bb7922ffcc2f38d3323dd9453ac49b55441452aeaca147711b2058e1d5681b5b9e0074b0f9c8d2c68a069b920d74521e79765036d57666c559709f4ca411a3f52f4e0792fd9e792779856719215d3b32a762afe3d5b8c684af90d8ef3d795acd4b35d4366ab22e4ad335273aa59429e26929d0f64583474d9c8203e205db4f19b37b60121b83a7333706db86431c6d835849957ed8c3928ad7927dc7234fd11d3e8c36c59277c3e6f149d5cd3cfa9a62aee49f8130962b4b3b9195e8aa5b7827463722b8c153931579d3505566b4edf48d498e185f0509de15204bb53b8977e5f92a0bc372742c4830944a59b4fe6b1c0466e2a6dad122b5d2e2e83fcb0416df2e0599079ad4358237ebbcc051c1ef925d1bbe59ea035bb04bb099ab042fbcc9f89fa4ce34fafb910263660c6d9fff8bf13f5bf456a42547c1918ae4f2015274a7d0d8142d845f7470e120fc800a759415089bc8616b6237c0910485bfaa12fa2c55ec86d7543b3f1884807bb9f8d42a1dd32a4cfe7ac0956520edc9ef9a928b77bea47eacfa88ccbc7db57a01a72dceca1a5fba69947c87f84180f7b5123c02164cebec652f5d75505ea37815294b1e10ad935d116fcf906381332e52cfcf7d8d5e817243c7bd145cc10108c0c005844e0cf93ed0b2ba5d3970451154fd78a0fc8165ca7738d30141cc50fcff56f568c3c9ce3dd831a5357b8248dd1cc1133cb0acdfcd50f4382b5014bcb06c6f06a87a1387e67b5fcd8dcbe22304c3f6044fb716a80985d42c8d90eff4ee51b804ba6d0f3747a8871abd22c0c2e79f5c39b34325fcb28fd86fef7c0959ccb45066a2dd0987ff1ecaa56de902633fe81d90d8002a06c501bcf5d564deb593969c83b8ad013d194f107a1c71d"),
         Err(PrecompileErrors::Error(PrecompileError::Bn128AffineGFailedToCreate)),
-    )
+    ),
+    // G2 point on the twist but not in the r-torsion subgroup
+    (
+        &hex!("0000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000002399f1a15a583763360c9421921eb3e226b5dcc22e2cc3dd88892326885aacba16ff49fbbcb3f246f8fceddd5b8e73c37fc338a49ad0561b30747a0f4d776cc910a76186ea08e3465e9ebec4c3f3505d3034cf7dd4338b17b4e6101f5e3e4389"),
+        Err(PrecompileErrors::Error(PrecompileError::Bn128AffineGFailedToCreate)),
+    ),
+    // G2 point not on the twist
+    (
+        &hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c21800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975c12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"),
+        Err(PrecompileErrors::Error(PrecompileError::Bn128AffineGFailedToCreate)),
+    ),
+    // G2 x imaginary coordinate equal to p
+    (
+        &hex!("0000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000230644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd471800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"),
+        Err(PrecompileErrors::Error(PrecompileError::Bn128FieldPointNotAMember)),
+    ),
+    // G2 y real coordinate greater than p
+    (
+        &hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c21800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b432cad18bcbe0e1502fbb7370f4c98ed7b5351fa74b59e08890758183f777af1"),
+        Err(PrecompileErrors::Error(PrecompileError::Bn128FieldPointNotAMember)),
+    ),
+    // G1 point not on curve
+    (
+        &hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000003198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c21800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"),
+        Err(PrecompileErrors::Error(PrecompileError::Bn128AffineGFailedToCreate)),
+    ),
+    // G1 x coordinate equal to p
+    (
+        &hex!("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd470000000000000000000000000000000000000000000000000000000000000002198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c21800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"),
+        Err(PrecompileErrors::Error(PrecompileError::Bn128FieldPointNotAMember)),
+    ),
+    // 191 bytes
+    (
+        &hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c21800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7d"),
+        Err(PrecompileErrors::Error(PrecompileError::Bn128PairLength)),
+    ),
+    // 193 bytes
+    (
+        &hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c21800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa00"),
+        Err(PrecompileErrors::Error(PrecompileError::Bn128PairLength)),
+    ),
+];
+
+/// Pair counts of the generated ecPairing inputs
+#[cfg(feature = "pairing-scaling")]
+const ECPAIRING_SCALING_PAIR_COUNTS: &[usize] = &[1, 2, 4, 8, 16, 32, 64, 128];
+
+/// Uncompressed bn254 G1 generator, in ecPairing encoding
+#[cfg(feature = "pairing-scaling")]
+const BN254_G1_GENERATOR: [u8; 64] = hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002");
+/// Uncompressed bn254 G2 generator, in ecPairing encoding
+#[cfg(feature = "pairing-scaling")]
+const BN254_G2_GENERATOR: [u8; 128] = hex!("198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c21800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa");
+
+/// G2 points of the generated ecPairing inputs.
+/// Each entry consists of (scalar b, b * G2 in ecPairing encoding), computed in Python.
+#[cfg(feature = "pairing-scaling")]
+const ECPAIRING_SCALING_G2_POINTS: &[([u8; 32], [u8; 128])] = &[
+    (
+        hex!("2478936a6036556b645fb1df9063f9fe453e07331934f7ad341a82962dba1030"),
+        hex!("2f51aecfaa1978aafc0c4a2b6d2f932873d577e0f2e277fc8bd9e8d831f5c36c26f00b49e3d3ff6c2b5c95a110eb05795a441ca0143d3bd168fe939b1a5a8df9164fc520476fdbd2d57c25633f3943fe3312e44b2a2a9d9dad6702fb7e4a8bc530394081a832b5b35e370fe235896ece8f61d4f12329b890880e7f6a2f25f174"),
+    ),
+    (
+        hex!("2f3f82c321b6c4e8ed1dce23fcf384cac9c8626f1fb16cc5d58076fd0365be6f"),
+        hex!("2a4c39c9eb87c836cb59f9df0e4aae4f76de6fdae79b38c05f8016915b3642cc22266254c3bb0308afc404eb0a163bd2549e407b2ce2800ca6e1755f7dee932a1ecefb413c286f8fb5656b59dd3e77d6accd63080fae98821b01dfc331bc34821363637e110df50cf73e05a7104d65bb91a03e6988f82ef56223dec2a63aacea"),
+    ),
+    (
+        hex!("18044d7eaa0984da19c1e61d29a27afef098017f008e1beddc4fdcb3a7b24951"),
+        hex!("23d1c434eeb86c5c33580e91b502d12ac814688295940238e0888e82c2b0bd8a1bc4ba1ab9f0a9ad6bb19bc2acd53a832536832fe6cdbec65ec41a81daf5a5b51b422901e4d0e0fe267b2ae93dce7ad6de9f4e4f2c78de3b44419682f6d471c32cfb1dab9758352e2554cdd66915d1af4121e1ee2de62f8be6ed2f5343548d80"),
+    ),
+    (
+        hex!("2a3b045a5c901a46a8fd6201fa95c36d292ba3a6c08cdf425a89b0a7c629c7ea"),
+        hex!("055c7f8fb3759671d6b72fbad9f21b72d1f7273dce8726ec63ba61ee216658c630253bbc873590d952bf9d68a7662403e0ab8880e6ff78ba1447838b2608cbef0d5e054280518469571d11afec276aeec5cd94a5f1e64a1a72344bcfba9c39a01a76da44980db950baec62ce6d819063b374099a55bc50e5abcbb262629bb38c"),
+    ),
 ];
 
 /// Run all ecAdd test cases
 pub fn run_ecadd_tests() {
     for (input, expected) in ECADD_CASES {
-        let outcome = run_add(input, 0, 0).unwrap();
-        assert_eq!(outcome.bytes.as_ref(), expected.as_slice());
+        check_result(run_add(input, 0, 0), expected);
     }
 }
 
 /// Run all ecMul test cases
 pub fn run_ecmul_tests() {
     for (input, expected) in ECMUL_CASES {
-        let outcome = run_mul(input, 0, 0).unwrap();
-        assert_eq!(outcome.bytes.as_ref(), expected.as_slice());
+        check_result(run_mul(input, 0, 0), expected);
     }
 }
 
 /// Run all ecPairing test cases
 pub fn run_ecpairing_tests() {
     for (input, expected) in ECPAIRING_CASES {
-        let result = run_pair(input, 0, 0, u64::MAX);
+        check_result(run_pair(input, 0, 0, u64::MAX), expected);
+    }
+}
+
+/// Run ecPairing on generated inputs of increasing pair counts, with cycle markers per pair count.
+/// Pair i is (a_i * G1, b_i * G2) with a_i = i + 1 and b_i cycling through `ECPAIRING_SCALING_G2_POINTS`.
+/// The last pair (-sum(a_i * b_i) * G1, G2) makes the product of pairings one; adding one to its scalar
+/// makes the check fail.
+#[cfg(feature = "pairing-scaling")]
+pub fn run_ecpairing_scaling_tests() {
+    for &pairs in ECPAIRING_SCALING_PAIR_COUNTS {
+        let mut input = Vec::with_capacity(pairs * 192);
+        let mut exponent = Scalar::ZERO;
+        for i in 0..pairs - 1 {
+            let (b, g2_point) = &ECPAIRING_SCALING_G2_POINTS[i % ECPAIRING_SCALING_G2_POINTS.len()];
+            let a = Scalar::from_u32(i as u32 + 1);
+            input.extend_from_slice(&g1_multiple(&a));
+            input.extend_from_slice(g2_point);
+            exponent += a * Scalar::from_be_bytes(b);
+        }
+
+        for valid in [true, false] {
+            let closing_scalar = if valid {
+                -exponent.clone()
+            } else {
+                -exponent.clone() + Scalar::ONE
+            };
+            let mut input = input.clone();
+            input.extend_from_slice(&g1_multiple(&closing_scalar));
+            input.extend_from_slice(&BN254_G2_GENERATOR);
+
+            let label = format!("ecPairing {pairs} pairs, valid = {valid}");
+            cycle_tracker::start(&label);
+            let outcome = run_pair(&input, 0, 0, u64::MAX).unwrap();
+            cycle_tracker::end(&label);
+
+            let mut expected = [0u8; 32];
+            expected[31] = valid as u8;
+            assert_eq!(outcome.bytes.as_ref(), expected.as_slice());
+        }
+    }
+}
+
+/// Compute `scalar * G1` with the ecMul precompile
+#[cfg(feature = "pairing-scaling")]
+fn g1_multiple(scalar: &Scalar) -> [u8; 64] {
+    let input = [BN254_G1_GENERATOR.as_slice(), &scalar.to_be_bytes()].concat();
+    let outcome = run_mul(&input, 0, 0).unwrap();
+    outcome.bytes.as_ref().try_into().unwrap()
+}
 
-        match (result, expected) {
-            (Ok(output), Ok(expected_bytes)) => {
-                assert_eq!(output.bytes.as_ref(), *expected_bytes);
-            }
-            (Err(error), Err(expected_error)) => {
-                assert_eq!(error, *expected_error);
-            }
-            (Ok(output), Err(expected_error)) => {
-                panic!("Expected error {expected_error:?}, but got success: {output:?}");
-            }
-            (Err(error), Ok(expected_bytes)) => {
-                panic!("Expected success with bytes {expected_bytes:?}, but got error: {error:?}");
-            }
+/// Compare a precompile result with the expected output bytes or error
+fn check_result(result: PrecompileResult, expected: &TestCaseOutput) {
+    match (result, expected) {
+        (Ok(output), Ok(expected_bytes)) => {
+            assert_eq!(output.bytes.as_ref(), *expected_bytes);
+        }
+        (Err(error), Err(expected_error)) => {
+            assert_eq!(error, *expected_error);
+        }
+        (Ok(output), Err(expected_error)) => {
+            panic!("Expected error {expected_error:?}, but got success: {output:?}");
+        }
+        (Err(error), Ok(expected_bytes)) => {
+            panic!("Expected success with bytes {expected_bytes:?}, but got error: {error:?}");
         }
     }
 }
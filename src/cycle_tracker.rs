@@ -0,0 +1,11 @@
+//! Cycle count markers picked up by the openvm executor when profiling is enabled.
+
+/// Start counting cycles under `label`
+pub fn start(label: &str) {
+    openvm::io::println(format!("cycle-tracker-start: {label}"));
+}
+
+/// Stop counting cycles under `label`
+pub fn end(label: &str) {
+    openvm::io::println(format!("cycle-tracker-end: {label}"));
+}
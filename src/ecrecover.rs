@@ -1,9 +1,21 @@
 use hex_literal::hex;
-use revm_precompile::{primitives::address, secp256k1::ec_recover_run, Address, Bytes};
+use openvm_algebra_guest::{DivUnsafe, IntMod};
+use openvm_ecc_guest::{
+    k256::{Secp256k1Point, Secp256k1Scalar},
+    msm,
+    weierstrass::WeierstrassPoint,
+    CyclicGroup,
+};
+use openvm_sha256_guest::sha256;
+use revm_precompile::{
+    primitives::{address, keccak256},
+    secp256k1::ec_recover_run,
+    Address, Bytes,
+};
 
 /// Vector of test cases for ecRecover precompile.
 /// Each test case consists of (input_bytes, expected_address_result).
-const ECRECOVER_TEST_CASES: &[(&[u8], Option<Address>)] = &[
+pub(crate) const ECRECOVER_TEST_CASES: &[(&[u8], Option<Address>)] = &[
     // "ethereum/tests/CALLCODEEcrecover0"
     (
         &hex!("18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c000000000000000000000000000000000000000000000000000000000000001c73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75feeb940b1d03b21e36b0e47e79769f095fe2ab855bd91e3a38756b7d75a9c4549"),
@@ -54,8 +66,180 @@ const ECRECOVER_TEST_CASES: &[(&[u8], Option<Address>)] = &[
         &hex!("18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c000000000000000000000000000000000000001000000000000000000000011c73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75feeb940b1d03b21e36b0e47e79769f095fe2ab855bd91e3a38756b7d75a9c4549"),
         None,
     ),
+    // low-s form of ValidKey: s -> n - s flips the recovery id
+    (
+        &hex!("18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c000000000000000000000000000000000000000000000000000000000000001b73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75f1146bf4e2fc4de1c94f1b81868960f68bc842490f1b6bc98387ba6b57599fbf8"),
+        Some(address!("a94f5374fce5edbc8e2a8697c15331677e6ebf0b")),
+    ),
+    // low-s with the original recovery id recovers a different key
+    (
+        &hex!("18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c000000000000000000000000000000000000000000000000000000000000001c73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75f1146bf4e2fc4de1c94f1b81868960f68bc842490f1b6bc98387ba6b57599fbf8"),
+        Some(address!("d1554bb6114b61b5427d841a799776d3c065aa19")),
+    ),
+    // high-s with the flipped recovery id recovers a different key
+    (
+        &hex!("18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c000000000000000000000000000000000000000000000000000000000000001b73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75feeb940b1d03b21e36b0e47e79769f095fe2ab855bd91e3a38756b7d75a9c4549"),
+        Some(address!("d1554bb6114b61b5427d841a799776d3c065aa19")),
+    ),
+];
+
+/// Message hash signed over by the ecRecover matrix, taken from "ValidKey"
+const ECRECOVER_MATRIX_HASH: [u8; 32] =
+    hex!("18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c");
+
+/// Values of v in the ecRecover matrix, of which only 27 and 28 are accepted
+const ECRECOVER_MATRIX_V: &[u8] = &[0, 1, 26, 27, 28, 29, 255];
+
+/// Values of s in the ecRecover matrix, of which only 1 and n - 1 lie in [1, n)
+const ECRECOVER_MATRIX_S: &[[u8; 32]] = &[
+    // 0
+    hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    // 1
+    hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+    // n - 1
+    hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140"),
+    // n
+    hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"),
+    // n + 1
+    hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364142"),
+    // 2^256 - 1
+    hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+];
+
+/// Values of r in the ecRecover matrix: the values of s, plus the boundary p - n below which
+/// x = r + n is also a valid coordinate. Ethereum never recovers from x = r + n, and of these
+/// values only 1 and p - n in [1, n) are x coordinates of curve points.
+const ECRECOVER_MATRIX_R: &[[u8; 32]] = &[
+    // 0
+    hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    // 1
+    hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+    // n - 1
+    hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140"),
+    // n
+    hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"),
+    // n + 1
+    hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364142"),
+    // 2^256 - 1
+    hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+    // p - n - 1
+    hex!("000000000000000000000000000000014551231950b75fc4402da1722fc9baed"),
+    // p - n
+    hex!("000000000000000000000000000000014551231950b75fc4402da1722fc9baee"),
+];
+
+/// Combinations of the ecRecover matrix that recover an address.
+/// Each entry consists of (v, r, s, expected_address), every other combination must return empty output.
+/// Expected addresses computed with a Python secp256k1 public key recovery.
+const ECRECOVER_MATRIX_RECOVERED: &[(u8, [u8; 32], [u8; 32], Address)] = &[
+    // v = 27, r = 1, s = 1
+    (
+        27,
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        address!("f51a3604693bc72ff9506a50c436a6305d3aac0f"),
+    ),
+    // v = 27, r = 1, s = n - 1
+    (
+        27,
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140"),
+        address!("284813b5b81d57d8c0ecd1e8ce727c0fc50121b9"),
+    ),
+    // v = 27, r = p - n, s = 1
+    (
+        27,
+        hex!("000000000000000000000000000000014551231950b75fc4402da1722fc9baee"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        address!("500e9ab32f115de04554e0ebbec5acf339aa15fb"),
+    ),
+    // v = 27, r = p - n, s = n - 1
+    (
+        27,
+        hex!("000000000000000000000000000000014551231950b75fc4402da1722fc9baee"),
+        hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140"),
+        address!("c80132ef937bd91b5b53fcaa44a120aa7aff0e6a"),
+    ),
+    // v = 28, r = 1, s = 1
+    (
+        28,
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        address!("284813b5b81d57d8c0ecd1e8ce727c0fc50121b9"),
+    ),
+    // v = 28, r = 1, s = n - 1
+    (
+        28,
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140"),
+        address!("f51a3604693bc72ff9506a50c436a6305d3aac0f"),
+    ),
+    // v = 28, r = p - n, s = 1
+    (
+        28,
+        hex!("000000000000000000000000000000014551231950b75fc4402da1722fc9baee"),
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        address!("c80132ef937bd91b5b53fcaa44a120aa7aff0e6a"),
+    ),
+    // v = 28, r = p - n, s = n - 1
+    (
+        28,
+        hex!("000000000000000000000000000000014551231950b75fc4402da1722fc9baee"),
+        hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140"),
+        address!("500e9ab32f115de04554e0ebbec5acf339aa15fb"),
+    ),
+];
+
+/// secp256k1 group order n, big-endian
+const SECP256K1_ORDER: [u8; 32] =
+    hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141");
+/// floor(n / 2), the largest low-s value, big-endian
+const SECP256K1_HALF_ORDER: [u8; 32] =
+    hex!("7fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a0");
+
+/// Vector of known-answer test cases for the RFC 6979 secp256k1/SHA-256 signer.
+/// Each test case consists of (private_key, message_hash, r, low_s, recovery_id).
+/// "trezor-crypto/tests and python-ecdsa deterministic signature vectors"
+const RFC6979_TEST_CASES: &[([u8; 32], [u8; 32], [u8; 32], [u8; 32], u8)] = &[
+    // sha256("Satoshi Nakamoto") signed with key 1
+    (
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        hex!("a0dc65ffca799873cbea0ac274015b9526505daaaed385155425f7337704883e"),
+        hex!("934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8"),
+        hex!("2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5"),
+        1,
+    ),
+    // sha256("Satoshi Nakamoto") signed with key n - 1
+    (
+        hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140"),
+        hex!("a0dc65ffca799873cbea0ac274015b9526505daaaed385155425f7337704883e"),
+        hex!("fd567d121db66e382991534ada77a6bd3106f0a1098c231e47993447cd6af2d0"),
+        hex!("6b39cd0eb1bc8603e159ef5c20a5c8ad685a45b06ce9bebed3f153d10d93bed5"),
+        0,
+    ),
+    // sha256("All those moments will be lost in time, like tears in rain. Time to die...") signed with key 1
+    (
+        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        hex!("7d1833f54854ac51659521afcd0ec6dca2ce2351429614bfa28a756b1b3c637f"),
+        hex!("8600dbd41e348fe5c9465ab92d23e3db8b98b873beecd930736488696438cb6b"),
+        hex!("547fe64427496db33bf66019dacbf0039c04199abb0122918601db38a72cfc21"),
+        0,
+    ),
+    // sha256("Computer science is no more about computers than astronomy is about telescopes.")
+    (
+        hex!("69ec59eaa1f4f2e36b639716b7c30ca86d9a5375c7b38d8918bd9c0ebc80ba64"),
+        hex!("24833a5c2c927c9876d7a77e400577057598e0d7b0c96587ee8a37ea5381bede"),
+        hex!("7186363571d65e084e7f02b0b77c3ec44fb1b257dee26274c38c928986fea45d"),
+        hex!("0de0b38e06807e46bda1f1e293f4f6323e854c86d58abdd00c46c16441085df6"),
+        0,
+    ),
 ];
 
+/// Seed the round-trip private keys and message hashes are derived from
+const ECRECOVER_ROUND_TRIP_SEED: &[u8] = b"openvm-tests ecrecover round trip";
+/// Number of keys signed with and recovered in the round-trip test
+const ECRECOVER_ROUND_TRIP_CASES: u32 = 256;
+
 /// Run all ecRecover function test cases
 pub fn run_ecrecover_tests() {
     for (input, expected) in ECRECOVER_TEST_CASES {
@@ -68,3 +252,148 @@ pub fn run_ecrecover_tests() {
         }
     }
 }
+
+/// Run ecRecover on every combination of v, r and s in the matrix, checking Ethereum's
+/// acceptance rules for the recovery id and signature scalars
+pub fn run_ecrecover_matrix_tests() {
+    for v in ECRECOVER_MATRIX_V {
+        for r in ECRECOVER_MATRIX_R {
+            for s in ECRECOVER_MATRIX_S {
+                let mut input = [0u8; 128];
+                input[..32].copy_from_slice(&ECRECOVER_MATRIX_HASH);
+                input[63] = *v;
+                input[64..96].copy_from_slice(r);
+                input[96..].copy_from_slice(s);
+
+                let result = ec_recover_run(&Bytes::copy_from_slice(&input), u64::MAX).unwrap();
+
+                let expected = ECRECOVER_MATRIX_RECOVERED
+                    .iter()
+                    .find(|(ev, er, es, _)| ev == v && er == r && es == s)
+                    .map(|(_, _, _, address)| address);
+                match expected {
+                    Some(address) => {
+                        assert_eq!(&result.bytes[..12], &[0u8; 12]);
+                        assert_eq!(Address::from_slice(&result.bytes[12..]), *address);
+                    }
+                    None => assert!(result.bytes.is_empty()),
+                }
+            }
+        }
+    }
+}
+
+/// Run ecRecover round-trip test cases: derive keys from a seed, sign hashes in the guest with
+/// the deterministic RFC 6979 signer and check that ecRecover returns the signer's address
+pub fn run_ecrecover_round_trip_tests() {
+    for (private_key, message_hash, r, s, recovery_id) in RFC6979_TEST_CASES {
+        assert_eq!(
+            sign_prehash(private_key, message_hash),
+            (*r, *s, *recovery_id)
+        );
+    }
+
+    for index in 0..ECRECOVER_ROUND_TRIP_CASES {
+        let private_key = derive_private_key(index);
+        let message_hash =
+            keccak256([ECRECOVER_ROUND_TRIP_SEED, b"message", &index.to_be_bytes()].concat()).0;
+        let (r, s, recovery_id) = sign_prehash(&private_key, &message_hash);
+
+        let mut input = [0u8; 128];
+        input[..32].copy_from_slice(&message_hash);
+        input[63] = 27 + recovery_id;
+        input[64..96].copy_from_slice(&r);
+        input[96..].copy_from_slice(&s);
+        let result = ec_recover_run(&Bytes::copy_from_slice(&input), u64::MAX).unwrap();
+
+        assert_eq!(
+            Address::from_slice(&result.bytes[12..]),
+            public_key_address(&private_key)
+        );
+    }
+}
+
+/// Derive the private key with the given index from the round-trip seed,
+/// rehashing until it lies in [1, n)
+fn derive_private_key(index: u32) -> [u8; 32] {
+    let mut counter = 0u32;
+    loop {
+        let key = keccak256(
+            [
+                ECRECOVER_ROUND_TRIP_SEED,
+                b"key",
+                &index.to_be_bytes(),
+                &counter.to_be_bytes(),
+            ]
+            .concat(),
+        )
+        .0;
+        if key != [0u8; 32] && key < SECP256K1_ORDER {
+            return key;
+        }
+        counter += 1;
+    }
+}
+
+/// Ethereum address of a private key: the last 20 bytes of keccak256(x || y) of its public key
+fn public_key_address(private_key: &[u8; 32]) -> Address {
+    let d = Secp256k1Scalar::from_be_bytes(private_key);
+    let public_key = msm(&[d], &[Secp256k1Point::GENERATOR]);
+    let encoded = [public_key.x().to_be_bytes(), public_key.y().to_be_bytes()].concat();
+    Address::from_slice(&keccak256(encoded)[12..])
+}
+
+/// Sign a 32-byte message hash with an RFC 6979 deterministic nonce.
+/// Returns big-endian (r, s) with s normalized to the lower half of the order, and the recovery id.
+fn sign_prehash(private_key: &[u8; 32], message_hash: &[u8; 32]) -> ([u8; 32], [u8; 32], u8) {
+    let d = Secp256k1Scalar::from_be_bytes(private_key);
+    // adding zero reduces the hash modulo n, which is bits2octets for a 256-bit order
+    let z = Secp256k1Scalar::from_be_bytes(message_hash) + Secp256k1Scalar::ZERO;
+    let nonce = rfc6979_nonce(private_key, &z.to_be_bytes());
+    let k = Secp256k1Scalar::from_be_bytes(&nonce);
+
+    let big_r = msm(&[k.clone()], &[Secp256k1Point::GENERATOR]);
+    let r_bytes = big_r.x().to_be_bytes();
+    // ecRecover cannot express an x coordinate that was reduced modulo n
+    assert!(r_bytes < SECP256K1_ORDER);
+    let mut recovery_id = big_r.y().to_be_bytes()[31] & 1;
+
+    let r = Secp256k1Scalar::from_be_bytes(&r_bytes);
+    let s = (z + r * d).div_unsafe(k);
+    let mut s_bytes = s.to_be_bytes();
+    if s_bytes > SECP256K1_HALF_ORDER {
+        s_bytes = (-s).to_be_bytes();
+        recovery_id ^= 1;
+    }
+    (r_bytes, s_bytes, recovery_id)
+}
+
+/// RFC 6979 section 3.2 nonce generation with HMAC-SHA256, for a 256-bit order
+fn rfc6979_nonce(private_key: &[u8; 32], reduced_hash: &[u8; 32]) -> [u8; 32] {
+    let mut v = [0x01u8; 32];
+    let mut k = [0x00u8; 32];
+    k = hmac_sha256(&k, &[&v[..], &[0x00], private_key, reduced_hash].concat());
+    v = hmac_sha256(&k, &v);
+    k = hmac_sha256(&k, &[&v[..], &[0x01], private_key, reduced_hash].concat());
+    v = hmac_sha256(&k, &v);
+    loop {
+        v = hmac_sha256(&k, &v);
+        if v != [0u8; 32] && v < SECP256K1_ORDER {
+            return v;
+        }
+        k = hmac_sha256(&k, &[&v[..], &[0x00]].concat());
+        v = hmac_sha256(&k, &v);
+    }
+}
+
+/// HMAC-SHA256 with a 32-byte key, using the sha256 intrinsic
+fn hmac_sha256(key: &[u8; 32], message: &[u8]) -> [u8; 32] {
+    let mut inner_pad = [0x36u8; 64];
+    let mut outer_pad = [0x5cu8; 64];
+    for (i, byte) in key.iter().enumerate() {
+        inner_pad[i] ^= byte;
+        outer_pad[i] ^= byte;
+    }
+    let inner_hash = sha256(&[&inner_pad[..], message].concat());
+    sha256(&[&outer_pad[..], &inner_hash[..]].concat())
+}
@@ -0,0 +1,202 @@
+use hex_literal::hex;
+use openvm_algebra_guest::{
+    field::{ComplexConjugate, FieldExtension},
+    DivUnsafe, Field, IntMod,
+};
+use openvm_pairing_guest::{
+    bls12_381::{Fp as Bls12_381Fp, Fp2 as Bls12_381Fp2},
+    bn254::{Fp as Bn254Fp, Fp2 as Bn254Fp2},
+};
+
+/// Fp2 element given as big-endian `(c0, c1)` coefficients of `c0 + c1 * u`, with `u^2 = -1`.
+type Fp2Bytes = (&'static [u8], &'static [u8]);
+
+/// Known-answer test case for Fp2 arithmetic.
+/// Each test case consists of two operands and the expected results of every binary and unary operation.
+struct Fp2TestCase {
+    a: Fp2Bytes,
+    b: Fp2Bytes,
+    sum: Fp2Bytes,
+    difference: Fp2Bytes,
+    product: Fp2Bytes,
+    square: Fp2Bytes,
+    inverse: Fp2Bytes,
+    quotient: Fp2Bytes,
+}
+
+/// Vector of test cases for Bn254Fp2.
+/// Operands are sha256 digests of fixed labels reduced modulo p, expected values computed in Python.
+const BN254_FP2_TEST_CASES: &[Fp2TestCase] = &[Fp2TestCase {
+    a: (
+        &hex!("06046715ba68f52b06a688e80f520f9f3261de3db608caf0c4ec230fd7290a75"),
+        &hex!("16b451d4579425b4fb81b40917f9aea4cd0090bdfbdefb9f0d4a7472a86ae38d"),
+    ),
+    b: (
+        &hex!("1fe68be108c7dab0d1ef953c55668a79c39d87d74a7059245e4096a5c3b9332c"),
+        &hex!("1adfdcd04288d0731e3328c9c034ebd1451b405eb6d3713be549a1df445b3efa"),
+    ),
+    sum: (
+        &hex!("25eaf2f6c330cfdbd8961e2464b89a18f5ff661500792415232cb9b59ae23da1"),
+        &hex!("012fe031b8eb55fe6164971c56ad42187a9a668b4a40a24db6738a3b14492540"),
+    ),
+    difference: (
+        &hex!("168229a792d2baa3ed0739623b6cdd830645c0f7d40a3c59a2cc1880ebecd490"),
+        &hex!("2c38c376f63cf56b959ed0f5d9461b311f66baf0ad7d54f064215eaa3c8ca1da"),
+    ),
+    product: (
+        &hex!("198efa25c482edb8fd611d214632986ba23af893b4b3540bb1a7d429fe9c4c9f"),
+        &hex!("0680ab6c2090791745556a4cd7ee955528cdf4b6eea1982c304fb99dba8531fd"),
+    ),
+    square: (
+        &hex!("22b36059a942f2c56b138055ce57a5114801e8f5eb56cc8387eaa9a269117a41"),
+        &hex!("17ea35c99b375dd2b7f9b4b391f3bcf25952c999aea258d48ea4dbba77d63550"),
+    ),
+    inverse: (
+        &hex!("10728f87bcce4bbcac1c4c9f2e85709f1560ea537fbfbfa47490ae236cc110e8"),
+        &hex!("05c45b387212bf94c00761c9fd60591a1dad0389c607fcad76f55cd2d9495288"),
+    ),
+    quotient: (
+        &hex!("1259c00c1984d8a4a3ebbfa8a00d664d855e88d8bdc37f5c0c18cd73ba2cf0d5"),
+        &hex!("157ca2cc0d2b97f800119aef611862293c6220e1e0c8f658681a49aa55a1e41e"),
+    ),
+}];
+
+/// Vector of test cases for Bls12_381Fp2.
+/// Operands are sha256 digests of fixed labels reduced modulo p, expected values computed in Python.
+const BLS12_381_FP2_TEST_CASES: &[Fp2TestCase] = &[Fp2TestCase {
+    a: (
+        &hex!("0d80287ef7a750315cc2b07b08e903163021eb49b7408f4d2294ddab5513e2d6f9c12919762aa1e96a5a6b707bc7ab22"),
+        &hex!("1a0081772eae051873b400f56641309462ac313ed17cb64d288c53b54ae738f20a1832581f4f8c691aa6aaf8e7015fdf"),
+    ),
+    b: (
+        &hex!("0ee29becd1bb4e156216938506cec486decf3fe8fcf61efd3c9a53b489d8ce36fc5ccc2f78a958fbf2e526131eb5d6ee"),
+        &hex!("05f6a2c9285de6aaa2f17f871757011e8f60cfb2c195f30f8425f7d8a201c66f7e26d3e2e343d7d2d7570379827a9398"),
+    ),
+    sum: (
+        &hex!("0261b2818fe2b7ac73bd9c49cc6c1ac5aa79dfadc0b19b8af7fe5ebee83bbae9d771f54a3d7ffae5a34091839a7dd765"),
+        &hex!("05f612561d8c0528cb89d8c63a4c84db8d95b56c9f8d969d458178ecf638093d6993063c513f643c37feae72697c48cc"),
+    ),
+    difference: (
+        &hex!("189e9e7c5f6be8b645c7c4ac4565eb66b5c9f6e5adcf830f4d2b5c97c1ec0ac41c105ce8aed548ed3174455d5d117edf"),
+        &hex!("1409deae06501e6dd0c2816e4eea2f75d34b618c0fe6c33da4665bdca8e572828bf15e753c0bb496434fa77f6486cc47"),
+    ),
+    product: (
+        &hex!("0e3788d485c0ebaacae98311f012bf74f5c00a601656c1b145824485a891cd6a68f556be78fc00bcb499883052feab20"),
+        &hex!("14f8b96874657107aedb0ea3731393bcdd632269ebd51440eb299ee49de83f77896308580c86b951565cb335b3d76389"),
+    ),
+    square: (
+        &hex!("104d396314d65948fcb1b649a0c5c5421d00e13853780fac841b994735c8d0b3294490f008c5c19f846f858a6cea392f"),
+        &hex!("1662b26f9206babc346d688a2970585421f3d4d6d3a917dfbd204741476f30a37093549b055643f8671b6fb87688ad00"),
+    ),
+    inverse: (
+        &hex!("089c33fdb3b9b31d6f4e34cb2bc96cc0667a394a82a592072471080a8bfa935e69f06a381968fce09a447ff5a1044e80"),
+        &hex!("0f35cb382872e6102d48bb6bc5054a7743e73f13fcf1ddfcab54a455dd87bd08743b66f3e3c514b439359805391c564f"),
+    ),
+    quotient: (
+        &hex!("07a2059c6a852644bdf11996d87c3451bde1db77318cd34cbb41b11c768bc9585925286b8462044e26c3f8f837f38ad1"),
+        &hex!("128f18c76935e565577bf6ff51b9bee53ab8b4f1f377ee199a7ae02c19bcd6277a7d2ba91a1deb77b5db876f811c9ed0"),
+    ),
+}];
+
+/// Run all Fp2 test cases for both Bn254Fp2 and Bls12_381Fp2
+pub fn run_fp2_tests() {
+    for case in BN254_FP2_TEST_CASES {
+        run_fp2_test_case::<Bn254Fp, _>(case, |(c0, c1)| {
+            Bn254Fp2::new(Bn254Fp::from_be_bytes(c0), Bn254Fp::from_be_bytes(c1))
+        });
+    }
+    for case in BLS12_381_FP2_TEST_CASES {
+        run_fp2_test_case::<Bls12_381Fp, _>(case, |(c0, c1)| {
+            Bls12_381Fp2::new(
+                Bls12_381Fp::from_be_bytes(c0),
+                Bls12_381Fp::from_be_bytes(c1),
+            )
+        });
+    }
+}
+
+/// Check the known-answer values of a test case, then the field identities on its operands.
+fn run_fp2_test_case<Fp, F>(case: &Fp2TestCase, to_fp2: impl Fn(&Fp2Bytes) -> F)
+where
+    Fp: Field,
+    F: Field + FieldExtension<Fp, Coeffs = [Fp; 2]> + ComplexConjugate,
+{
+    let a = to_fp2(&case.a);
+    let b = to_fp2(&case.b);
+
+    assert_eq!(a.clone() + b.clone(), to_fp2(&case.sum));
+    assert_eq!(a.clone() - b.clone(), to_fp2(&case.difference));
+    assert_eq!(a.clone() * b.clone(), to_fp2(&case.product));
+    assert_eq!(a.clone().div_unsafe(b.clone()), to_fp2(&case.quotient));
+    assert_eq!(F::ONE.div_unsafe(a.clone()), to_fp2(&case.inverse));
+
+    let mut square = a.clone();
+    square.square_assign();
+    assert_eq!(square, to_fp2(&case.square));
+
+    check_fp2_identities::<Fp, F>(&a, &b);
+}
+
+/// Check ring, inverse, conjugation and frobenius identities that hold for any nonzero `a`, `b` in Fp2.
+fn check_fp2_identities<Fp, F>(a: &F, b: &F)
+where
+    Fp: Field,
+    F: Field + FieldExtension<Fp, Coeffs = [Fp; 2]> + ComplexConjugate,
+{
+    // additive group
+    assert_eq!(a.clone() + b.clone(), b.clone() + a.clone());
+    assert_eq!(a.clone() + F::ZERO, a.clone());
+    assert_eq!(a.clone() - a.clone(), F::ZERO);
+    assert_eq!(a.clone() + (-a.clone()), F::ZERO);
+    assert_eq!((a.clone() - b.clone()) + b.clone(), a.clone());
+
+    let mut double = a.clone();
+    double.double_assign();
+    assert_eq!(double, a.clone() + a.clone());
+
+    // multiplicative group
+    assert_eq!(a.clone() * b.clone(), b.clone() * a.clone());
+    assert_eq!(a.clone() * F::ONE, a.clone());
+    assert_eq!(a.clone() * F::ZERO, F::ZERO);
+    assert_eq!(
+        a.clone() * (a.clone() + b.clone()),
+        a.clone() * a.clone() + a.clone() * b.clone()
+    );
+
+    let mut square = a.clone();
+    square.square_assign();
+    assert_eq!(square, a.clone() * a.clone());
+
+    let a_inv = F::ONE.div_unsafe(a.clone());
+    assert_eq!(a.clone() * a_inv.clone(), F::ONE);
+    assert_eq!(b.clone().div_unsafe(a.clone()), b.clone() * a_inv);
+    assert_eq!((a.clone() * b.clone()).div_unsafe(b.clone()), a.clone());
+
+    // u^2 = -1
+    let u = F::from_coeffs([Fp::ZERO, Fp::ONE]);
+    assert_eq!(u.clone() * u, -F::ONE);
+
+    // conjugation is an involutive field automorphism fixing Fp
+    assert_eq!(a.clone().conjugate().conjugate(), a.clone());
+    assert_eq!(
+        (a.clone() * b.clone()).conjugate(),
+        a.clone().conjugate() * b.clone().conjugate()
+    );
+    assert_eq!(
+        (a.clone() + b.clone()).conjugate(),
+        a.clone().conjugate() + b.clone().conjugate()
+    );
+    let [_, norm_imaginary] = (a.clone() * a.clone().conjugate()).to_coeffs();
+    assert_eq!(norm_imaginary, Fp::ZERO);
+    let [_, trace_imaginary] = (a.clone() + a.clone().conjugate()).to_coeffs();
+    assert_eq!(trace_imaginary, Fp::ZERO);
+
+    // p = 3 mod 4, so the frobenius map is conjugation and has order 2
+    assert_eq!(a.frobenius_map(1), a.clone().conjugate());
+    assert_eq!(a.frobenius_map(2), a.clone());
+    assert_eq!(
+        (a.clone() * b.clone()).frobenius_map(1),
+        a.frobenius_map(1) * b.frobenius_map(1)
+    );
+    assert_eq!(F::embed(Fp::ONE), F::ONE);
+}
@@ -0,0 +1,152 @@
+use openvm::io::read_vec;
+use revm_precompile::bn128::{run_add, run_mul, run_pair};
+use revm_primitives::{B256, U256};
+use serde::Deserialize;
+
+use crate::cycle_tracker;
+
+/// bn254 base field modulus q
+const BN254_BASE_MODULUS: U256 = U256::from_limbs([
+    0x3c208c16d87cfd47,
+    0x97816a916871ca8d,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+]);
+/// bn254 scalar field modulus r, public inputs must be below it
+const BN254_SCALAR_MODULUS: U256 = U256::from_limbs([
+    0x43e1f593f0000001,
+    0x2833e84879b97091,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+]);
+
+/// G1 point in snarkjs JSON: decimal projective coordinates `[x, y, z]`
+type G1Json = [String; 3];
+/// G2 point in snarkjs JSON: decimal `[[x.c0, x.c1], [y.c0, y.c1], [z.c0, z.c1]]`
+type G2Json = [[String; 2]; 3];
+
+/// snarkjs `verification_key.json`
+#[derive(Deserialize)]
+struct VerificationKey {
+    vk_alpha_1: G1Json,
+    vk_beta_2: G2Json,
+    vk_gamma_2: G2Json,
+    vk_delta_2: G2Json,
+    #[serde(rename = "IC")]
+    ic: Vec<G1Json>,
+}
+
+/// snarkjs `proof.json`
+#[derive(Deserialize)]
+struct Proof {
+    pi_a: G1Json,
+    pi_b: G2Json,
+    pi_c: G1Json,
+}
+
+/// Run Groth16 verifications read from stdin, see `scripts/groth16_inputs.py`.
+/// The input stream holds the snarkjs verification key, a 4-byte little-endian case count,
+/// then for each case the snarkjs proof and public inputs and one byte that is 1 if the proof is valid.
+/// Cycles are reported for every verification.
+/// `scripts/groth16/prove.sh` produces a real snarkjs input; `inputs/groth16_simulated.json` comes from a
+/// simulated setup and only checks self-consistency.
+pub fn run_groth16_tests() {
+    let verification_key: VerificationKey =
+        serde_json::from_slice(&read_vec()).expect("invalid verification key");
+
+    let count = u32::from_le_bytes(read_vec().try_into().expect("count must be 4 bytes"));
+    for index in 0..count {
+        let proof: Proof = serde_json::from_slice(&read_vec()).expect("invalid proof");
+        let public_inputs: Vec<String> =
+            serde_json::from_slice(&read_vec()).expect("invalid public inputs");
+        let valid = read_vec() == [1];
+
+        let label = format!("groth16 verify #{index}");
+        cycle_tracker::start(&label);
+        let verified = verify_groth16(&verification_key, &proof, &public_inputs);
+        cycle_tracker::end(&label);
+
+        assert_eq!(verified, valid, "groth16 case {index}");
+    }
+}
+
+/// Verify a Groth16 proof with the bn128 precompiles, in the order of the snarkjs Solidity verifier:
+/// reject public inputs not below r, compute `vk_x = IC[0] + sum(input[i] * IC[i + 1])` with ecMul
+/// and ecAdd, then check `e(-A, B) * e(alpha, beta) * e(vk_x, gamma) * e(C, delta) == 1` with one
+/// 4-pair ecPairing call. A failing precompile call makes the verification fail.
+fn verify_groth16(vk: &VerificationKey, proof: &Proof, public_inputs: &[String]) -> bool {
+    if public_inputs.len() + 1 != vk.ic.len() {
+        return false;
+    }
+
+    let mut vk_x = encode_g1(&vk.ic[0]);
+    for (input, ic) in public_inputs.iter().zip(&vk.ic[1..]) {
+        let input = decimal(input);
+        if input >= BN254_SCALAR_MODULUS {
+            return false;
+        }
+        let mul_input = [encode_g1(ic).as_slice(), &input.to_be_bytes::<32>()].concat();
+        let Ok(product) = run_mul(&mul_input, 0, 0) else {
+            return false;
+        };
+        let Ok(sum) = run_add(&[vk_x.as_slice(), &product.bytes].concat(), 0, 0) else {
+            return false;
+        };
+        vk_x = sum.bytes.as_ref().try_into().unwrap();
+    }
+
+    let pairing_input = [
+        negate_g1(&proof.pi_a).as_slice(),
+        &encode_g2(&proof.pi_b),
+        &encode_g1(&vk.vk_alpha_1),
+        &encode_g2(&vk.vk_beta_2),
+        &vk_x,
+        &encode_g2(&vk.vk_gamma_2),
+        &encode_g1(&proof.pi_c),
+        &encode_g2(&vk.vk_delta_2),
+    ]
+    .concat();
+    match run_pair(&pairing_input, 0, 0, u64::MAX) {
+        Ok(output) => output.bytes.as_ref() == B256::with_last_byte(1).as_slice(),
+        Err(_) => false,
+    }
+}
+
+/// Negation `(x, q - y)` of an affine G1 point, as big-endian `x || y`
+fn negate_g1(point: &G1Json) -> [u8; 64] {
+    let y = decimal(&point[1]);
+    let negated_y = if y.is_zero() {
+        y
+    } else {
+        BN254_BASE_MODULUS - y
+    };
+    let mut encoded = encode_g1(point);
+    encoded[32..].copy_from_slice(&negated_y.to_be_bytes::<32>());
+    encoded
+}
+
+/// Affine G1 point as big-endian `x || y`
+fn encode_g1(point: &G1Json) -> [u8; 64] {
+    assert_eq!(point[2], "1", "G1 point must be affine");
+    let mut encoded = [0u8; 64];
+    encoded[..32].copy_from_slice(&decimal(&point[0]).to_be_bytes::<32>());
+    encoded[32..].copy_from_slice(&decimal(&point[1]).to_be_bytes::<32>());
+    encoded
+}
+
+/// Affine G2 point as big-endian `x.c1 || x.c0 || y.c1 || y.c0`, imaginary part first as in EIP-197
+fn encode_g2(point: &G2Json) -> [u8; 128] {
+    assert_eq!(point[2], ["1", "0"], "G2 point must be affine");
+    let mut encoded = [0u8; 128];
+    for (i, coordinate) in point[..2].iter().enumerate() {
+        encoded[i * 64..i * 64 + 32].copy_from_slice(&decimal(&coordinate[1]).to_be_bytes::<32>());
+        encoded[i * 64 + 32..i * 64 + 64]
+            .copy_from_slice(&decimal(&coordinate[0]).to_be_bytes::<32>());
+    }
+    encoded
+}
+
+/// Parse a snarkjs decimal string
+fn decimal(value: &str) -> U256 {
+    U256::from_str_radix(value, 10).expect("invalid decimal field element")
+}
@@ -1,5 +1,5 @@
 use hex_literal::hex;
-use revm_primitives::keccak256;
+use revm_primitives::{alloy_primitives::Keccak256, keccak256};
 
 /// Vector of test cases for Keccak-256 hash function.
 /// Each test case consists of (input_bytes, expected_hash_result).
@@ -142,6 +142,139 @@ const KECCAK_TEST_CASES: &[(&[u8], [u8; 32])] = &[
     ),
 ];
 
+/// Vector of test cases around the 136-byte keccak-256 rate.
+/// Each test case consists of (input_length, expected_hash_result), where the input is the
+/// byte sequence `0, 1, 2, ...` (mod 256) of that length.
+const KECCAK_RATE_BOUNDARY_TEST_CASES: &[(usize, [u8; 32])] = &[
+    // 135 bytes
+    (
+        135,
+        hex!("cbdfd9dee5faad3818d6b06f95a219fd290b0e1706f6a82e5a595b9ce9faca62"),
+    ),
+    // 136 bytes
+    (
+        136,
+        hex!("7ce759f1ab7f9ce437719970c26b0a66ff11fe3e38e17df89cf5d29c7d7f807e"),
+    ),
+    // 137 bytes
+    (
+        137,
+        hex!("ac73d4fae68b8453f764007c1a20ce95994187861f0c3227a3a8e99a73a3b1db"),
+    ),
+    // 271 bytes
+    (
+        271,
+        hex!("7c974895b2a88303ff2dc6b58f438ceb0b298cac91099ac0539cc0f477506191"),
+    ),
+    // 272 bytes
+    (
+        272,
+        hex!("fdf2ec49e749960d3c8521a0219af8d03e30e2b3bf19bd16150ee0eaf133d66e"),
+    ),
+];
+
+/// keccak-f[1600] states from the XKCP KeccakF-1600-IntermediateValues.txt, recomputed in Python:
+/// the all-zero state after one permutation, then after a second one.
+/// Lanes are given in the order `A[x + 5 * y]`.
+const KECCAK_F_ZERO_STATE_PERMUTATIONS: [[u64; 25]; 2] = [
+    [
+        0xF1258F7940E1DDE7,
+        0x84D5CCF933C0478A,
+        0xD598261EA65AA9EE,
+        0xBD1547306F80494D,
+        0x8B284E056253D057,
+        0xFF97A42D7F8E6FD4,
+        0x90FEE5A0A44647C4,
+        0x8C5BDA0CD6192E76,
+        0xAD30A6F71B19059C,
+        0x30935AB7D08FFC64,
+        0xEB5AA93F2317D635,
+        0xA9A6E6260D712103,
+        0x81A57C16DBCF555F,
+        0x43B831CD0347C826,
+        0x01F22F1A11A5569F,
+        0x05E5635A21D9AE61,
+        0x64BEFEF28CC970F2,
+        0x613670957BC46611,
+        0xB87C5A554FD00ECB,
+        0x8C3EE88A1CCF32C8,
+        0x940C7922AE3A2614,
+        0x1841F924A2C509E4,
+        0x16F53526E70465C2,
+        0x75F644E97F30A13B,
+        0xEAF1FF7B5CECA249,
+    ],
+    [
+        0x2D5C954DF96ECB3C,
+        0x6A332CD07057B56D,
+        0x093D8D1270D76B6C,
+        0x8A20D9B25569D094,
+        0x4F9C4F99E5E7F156,
+        0xF957B9A2DA65FB38,
+        0x85773DAE1275AF0D,
+        0xFAF4F247C3D810F7,
+        0x1F1B9EE6F79A8759,
+        0xE4FECC0FEE98B425,
+        0x68CE61B6B9CE68A1,
+        0xDEEA66C4BA8F974F,
+        0x33C43D836EAFB1F5,
+        0xE00654042719DBD9,
+        0x7CF8A9F009831265,
+        0xFD5449A6BF174743,
+        0x97DDAD33D8994B40,
+        0x48EAD5FC5D0BE774,
+        0xE3B8C8EE55B7B03C,
+        0x91A0226E649E42E9,
+        0x900E3129E7BADD7B,
+        0x202A9EC5FAA3CCE8,
+        0x5B3402464E1C3DB6,
+        0x609F4E62A44C1059,
+        0x20D06CD26A8FBF5C,
+    ],
+];
+
+/// keccak-f[1600] round constants
+const KECCAK_F_ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808A,
+    0x8000000080008000,
+    0x000000000000808B,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008A,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000A,
+    0x000000008000808B,
+    0x800000000000008B,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800A,
+    0x800000008000000A,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// keccak-f[1600] rotation offsets of lane `A[x + 5 * y]`
+const KECCAK_F_ROTATIONS: [u32; 25] = [
+    0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
+];
+
+/// keccak-256 rate in bytes
+const KECCAK_RATE: usize = 136;
+
+/// Chunk sizes used to absorb each input incrementally, straddling the 136-byte rate
+const KECCAK_CHUNK_SIZES: &[usize] = &[1, 135, 136, 137];
+
+/// Number of incremental hashes with pseudo-random chunk sizes per input
+const KECCAK_RANDOM_CHUNKING_ROUNDS: u32 = 4;
+
 /// Run all Keccak-256 hash function test cases
 pub fn run_keccak_tests() {
     for (input, expected) in KECCAK_TEST_CASES {
@@ -149,3 +282,124 @@ pub fn run_keccak_tests() {
         assert_eq!(result, expected);
     }
 }
+
+/// Run incremental Keccak-256 hashing test cases, comparing every chunking of an input
+/// with the one-shot hash and the expected result
+pub fn run_keccak_streaming_tests() {
+    let rate_boundary_inputs: Vec<(Vec<u8>, [u8; 32])> = KECCAK_RATE_BOUNDARY_TEST_CASES
+        .iter()
+        .map(|(len, expected)| ((0..*len).map(|i| i as u8).collect(), *expected))
+        .collect();
+    let inputs = KECCAK_TEST_CASES
+        .iter()
+        .map(|(input, expected)| (*input, expected))
+        .chain(
+            rate_boundary_inputs
+                .iter()
+                .map(|(input, expected)| (input.as_slice(), expected)),
+        );
+
+    for (input, expected) in inputs {
+        assert_eq!(keccak256(input), expected);
+
+        for chunk_size in KECCAK_CHUNK_SIZES {
+            let mut hasher = Keccak256::new();
+            for chunk in input.chunks(*chunk_size) {
+                hasher.update(chunk);
+            }
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        for round in 0..KECCAK_RANDOM_CHUNKING_ROUNDS {
+            let mut hasher = Keccak256::new();
+            let mut rng = round.wrapping_add(input.len() as u32);
+            let mut remaining = input;
+            while !remaining.is_empty() {
+                // linear congruential generator for chunk sizes in 0..=300
+                rng = rng.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                let chunk_size = ((rng >> 16) % 301) as usize;
+                let (chunk, rest) = remaining.split_at(chunk_size.min(remaining.len()));
+                hasher.update(chunk);
+                remaining = rest;
+            }
+            assert_eq!(hasher.finalize(), expected);
+        }
+    }
+}
+
+/// Run keccak-f[1600] permutation test cases on a software permutation, then check that the
+/// keccak chip agrees with a software sponge over that permutation, on one-block inputs of every
+/// length up to the rate and on the multi-block rate boundary inputs.
+/// A permutation bug in the chip changes the hash of every one-block input, independently of padding.
+pub fn run_keccak_permutation_tests() {
+    let mut state = [0u64; 25];
+    for expected in &KECCAK_F_ZERO_STATE_PERMUTATIONS {
+        keccak_f(&mut state);
+        assert_eq!(&state, expected);
+    }
+
+    let inputs = (0..KECCAK_RATE)
+        .chain(KECCAK_RATE_BOUNDARY_TEST_CASES.iter().map(|(len, _)| *len))
+        .map(|len| (0..len).map(|i| (i * 7 + 3) as u8).collect::<Vec<u8>>());
+    for input in inputs {
+        assert_eq!(
+            keccak256(&input).0,
+            software_keccak256(&input),
+            "{} bytes",
+            input.len()
+        );
+    }
+}
+
+/// keccak-f[1600] permutation, lanes in the order `A[x + 5 * y]`
+fn keccak_f(state: &mut [u64; 25]) {
+    for round_constant in KECCAK_F_ROUND_CONSTANTS {
+        // theta
+        let mut parity = [0u64; 5];
+        for (x, column) in parity.iter_mut().enumerate() {
+            *column = (0..5).fold(0, |acc, y| acc ^ state[x + 5 * y]);
+        }
+        for (index, lane) in state.iter_mut().enumerate() {
+            let x = index % 5;
+            *lane ^= parity[(x + 4) % 5] ^ parity[(x + 1) % 5].rotate_left(1);
+        }
+
+        // rho and pi: lane (x, y) moves to (y, 2x + 3y)
+        let mut moved = [0u64; 25];
+        for (index, lane) in state.iter().enumerate() {
+            let (x, y) = (index % 5, index / 5);
+            moved[y + 5 * ((2 * x + 3 * y) % 5)] = lane.rotate_left(KECCAK_F_ROTATIONS[index]);
+        }
+
+        // chi
+        for (index, lane) in state.iter_mut().enumerate() {
+            let (x, row) = (index % 5, index - index % 5);
+            *lane = moved[index] ^ (!moved[row + (x + 1) % 5] & moved[row + (x + 2) % 5]);
+        }
+
+        // iota
+        state[0] ^= round_constant;
+    }
+}
+
+/// keccak-256 sponge over the software `keccak_f`, with the original keccak `0x01` padding
+fn software_keccak256(input: &[u8]) -> [u8; 32] {
+    let mut padded = input.to_vec();
+    padded.push(0x01);
+    padded.resize(padded.len().div_ceil(KECCAK_RATE) * KECCAK_RATE, 0);
+    *padded.last_mut().unwrap() |= 0x80;
+
+    let mut state = [0u64; 25];
+    for block in padded.chunks(KECCAK_RATE) {
+        for (lane, bytes) in state.iter_mut().zip(block.chunks(8)) {
+            *lane ^= u64::from_le_bytes(bytes.try_into().unwrap());
+        }
+        keccak_f(&mut state);
+    }
+
+    let mut digest = [0u8; 32];
+    for (bytes, lane) in digest.chunks_mut(8).zip(state) {
+        bytes.copy_from_slice(&lane.to_le_bytes());
+    }
+    digest
+}
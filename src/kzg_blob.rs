@@ -0,0 +1,140 @@
+use openvm::io::read_vec;
+use openvm_kzg::{Blob, Bytes48, KzgProof, KzgSettings};
+use revm_primitives::kzg::EnvKzgSettings;
+
+use crate::cycle_tracker;
+
+/// Result of a blob verification, mirroring the `true` / `false` / `null` outputs
+/// of the consensus-spec `verify_blob_kzg_proof` and `verify_blob_kzg_proof_batch` vectors.
+#[derive(Debug, PartialEq)]
+enum BlobVerification {
+    Invalid,
+    Valid,
+    /// malformed blob, commitment or proof
+    Error,
+}
+
+impl BlobVerification {
+    fn from_result<E>(result: Result<bool, E>) -> Self {
+        match result {
+            Ok(true) => Self::Valid,
+            Ok(false) => Self::Invalid,
+            Err(_) => Self::Error,
+        }
+    }
+
+    /// Decode the expected outcome byte written by `scripts/kzg_blob_inputs.py`
+    fn read() -> Self {
+        match read_vec().as_slice() {
+            [0] => Self::Invalid,
+            [1] => Self::Valid,
+            [2] => Self::Error,
+            other => panic!("invalid expected outcome {other:?}"),
+        }
+    }
+}
+
+/// Blob, commitment and proof bytes as read from stdin
+struct BlobInput {
+    blob: Vec<u8>,
+    commitment: Vec<u8>,
+    proof: Vec<u8>,
+}
+
+impl BlobInput {
+    fn read() -> Self {
+        Self {
+            blob: read_vec(),
+            commitment: read_vec(),
+            proof: read_vec(),
+        }
+    }
+
+    fn parse(&self) -> Option<(Blob, Bytes48, Bytes48)> {
+        Some((
+            Blob::from_slice(&self.blob).ok()?,
+            Bytes48::from_slice(&self.commitment).ok()?,
+            Bytes48::from_slice(&self.proof).ok()?,
+        ))
+    }
+}
+
+/// Run the blob verification vectors read from stdin, see `scripts/kzg_blob_inputs.py`.
+/// The input stream holds the number of `verify_blob_kzg_proof` cases, then for each case the blob,
+/// commitment, proof and expected outcome; followed by the number of `verify_blob_kzg_proof_batch`
+/// cases, then for each case the number of blobs, the blob inputs and the expected outcome.
+/// Counts are 4-byte little-endian. Cycles are reported for every verification.
+pub fn run_kzg_blob_tests() {
+    let settings = EnvKzgSettings::Default.get();
+
+    for index in 0..read_count() {
+        let input = BlobInput::read();
+        let expected = BlobVerification::read();
+
+        let label = format!("verify_blob_kzg_proof #{index}");
+        cycle_tracker::start(&label);
+        let outcome = verify_blob(&input, settings);
+        cycle_tracker::end(&label);
+
+        assert_eq!(outcome, expected, "verify_blob_kzg_proof case {index}");
+    }
+
+    for index in 0..read_count() {
+        let inputs: Vec<BlobInput> = (0..read_count()).map(|_| BlobInput::read()).collect();
+        let expected = BlobVerification::read();
+
+        let label = format!(
+            "verify_blob_kzg_proof_batch #{index} ({} blobs)",
+            inputs.len()
+        );
+        cycle_tracker::start(&label);
+        let outcome = verify_blob_batch(&inputs, settings);
+        cycle_tracker::end(&label);
+
+        assert_eq!(
+            outcome, expected,
+            "verify_blob_kzg_proof_batch case {index}"
+        );
+    }
+}
+
+fn verify_blob(input: &BlobInput, settings: &KzgSettings) -> BlobVerification {
+    let Some((blob, commitment, proof)) = input.parse() else {
+        return BlobVerification::Error;
+    };
+    BlobVerification::from_result(KzgProof::verify_blob_kzg_proof(
+        blob,
+        &commitment,
+        &proof,
+        settings,
+    ))
+}
+
+fn verify_blob_batch(inputs: &[BlobInput], settings: &KzgSettings) -> BlobVerification {
+    let Some(parsed) = inputs
+        .iter()
+        .map(BlobInput::parse)
+        .collect::<Option<Vec<_>>>()
+    else {
+        return BlobVerification::Error;
+    };
+    let mut blobs = Vec::with_capacity(parsed.len());
+    let mut commitments = Vec::with_capacity(parsed.len());
+    let mut proofs = Vec::with_capacity(parsed.len());
+    for (blob, commitment, proof) in parsed {
+        blobs.push(blob);
+        commitments.push(commitment);
+        proofs.push(proof);
+    }
+    BlobVerification::from_result(KzgProof::verify_blob_kzg_proof_batch(
+        blobs,
+        commitments,
+        proofs,
+        settings,
+    ))
+}
+
+fn read_count() -> u32 {
+    let bytes = read_vec();
+    u32::from_le_bytes(bytes.try_into().expect("count must be 4 bytes"))
+}
@@ -1,7 +1,13 @@
 use hex_literal::hex;
+#[cfg(feature = "kzg-custom-setup")]
+use openvm_kzg::KzgSettings;
 use revm_precompile::kzg_point_evaluation::run;
+#[cfg(feature = "kzg-custom-setup")]
+use revm_primitives::kzg::EnvKzgSettings;
 use revm_primitives::{Bytes, Env};
 use revm_primitives::{PrecompileError, PrecompileErrors};
+#[cfg(feature = "kzg-custom-setup")]
+use std::sync::Arc;
 
 /// Type alias for test case input
 type TestCaseInput = &'static [u8];
@@ -12,7 +18,7 @@ type KzgPointEvaluationTestCase = (TestCaseInput, TestCaseOutput);
 
 /// Vector of test cases for KZG point evaluation precompile.
 /// Each test case consists of (input_bytes, expected_output_bytes or expected_error).
-const KZG_POINT_EVALUATION_TEST_CASES: &[KzgPointEvaluationTestCase] = &[
+pub(crate) const KZG_POINT_EVALUATION_TEST_CASES: &[KzgPointEvaluationTestCase] = &[
     // https://github.com/ethereum/go-ethereum/blob/master/core/vm/testdata/precompiles/pointEvaluation.json
     (
         &hex!("01e798154708fe7789429634053cbf9f99b619f9f084048927333fce637f549b564c0a11a0f704f4fc3e8acfe0f8245f0ad1347b378fbf96e206da11a5d3630624d25032e67a7e6a4910df5834b8fe70e6bcfeeac0352434196bdf4b2485d5a18f59a8d2a1a625a17f3fea0fe5eb8c896db3764f3185481bc22f91b4aaffcca25f26936857bc3a7c2539ea8ec3a952b7873033e038326e87ed3e1276fd140253fa08e9fc25fb2d9a98527fc22a2c9612fbeafdad446cbc7bcdbdcd780af2c16a"),
@@ -25,14 +31,237 @@ const KZG_POINT_EVALUATION_TEST_CASES: &[KzgPointEvaluationTestCase] = &[
     ),
 ];
 
+/// Vector of synthetic test cases, not translated from the ethereum/consensus-spec-tests `verify_kzg_proof`
+/// suite; they cover correct and incorrect proofs, malformed points and non-canonical field elements.
+/// Each test case consists of (input_bytes, expected_output_bytes or expected_error).
+/// Commitments and proofs of low-degree polynomials computed in Python from the `[tau^i]G1` monomial
+/// points of the mainnet trusted setup, so every input carries the versioned hash of its commitment.
+/// Malformed points and out-of-range field elements are rejected as a failed verification.
+pub(crate) const KZG_SYNTHETIC_PROOF_TEST_CASES: &[KzgPointEvaluationTestCase] = &[
+    // correct proof - quadratic polynomial, z = 0
+    (
+        &hex!("01ad5c81cb0c297eb24fe3f2cc45e24b362c7e1837a36cda4ccbf8e88a5787c100000000000000000000000000000000000000000000000000000000000000003571bd070cb2cfa1112f1192975ed8fb73551d144dce038343e69d12ab31b78eb42d0ed7daf82f3e765803bddd3478fbfee55a512f6c0ffd16bb336861c5fee3d1b0b9f611da07f7c8e1ef0ddb498577b204546b3744694eb30b162b9f551519f66cf4700c4a9b48e7dded2a1007d409b167daa9e9b691aa0cfe156680ce1456"),
+        Ok(hex!("000000000000000000000000000000000000000000000000000000000000100073eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001")),
+    ),
+    // correct proof - quadratic polynomial, z = 1 (first root of unity of the blob domain)
+    (
+        &hex!("01ad5c81cb0c297eb24fe3f2cc45e24b362c7e1837a36cda4ccbf8e88a5787c100000000000000000000000000000000000000000000000000000000000000010c1d41382fef8ace8c56366c45db918b0d836e0d31e07038a9e1d27848878e8fb42d0ed7daf82f3e765803bddd3478fbfee55a512f6c0ffd16bb336861c5fee3d1b0b9f611da07f7c8e1ef0ddb498577b70e6d56fe7c668711bb1eb5098a8e2064a690c9260eb8185f6140325c1028c4a89060f5fea0acb9e7a861c7d45fb342"),
+        Ok(hex!("000000000000000000000000000000000000000000000000000000000000100073eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001")),
+    ),
+    // correct proof - quadratic polynomial, z = w (root of unity of order 4096)
+    (
+        &hex!("01ad5c81cb0c297eb24fe3f2cc45e24b362c7e1837a36cda4ccbf8e88a5787c1564c0a11a0f704f4fc3e8acfe0f8245f0ad1347b378fbf96e206da11a5d363060a0f8e927cc4a82d0f782699e8fcaff2f1a98b4dcf934a8c3d524f90e67cbfc4b42d0ed7daf82f3e765803bddd3478fbfee55a512f6c0ffd16bb336861c5fee3d1b0b9f611da07f7c8e1ef0ddb498577b53a79b71b14357365916946860d5713ac3b16cddbb20eacceee95d3eccfade55d2e8a7f7f0dd86a4ba0c0625df37bd7"),
+        Ok(hex!("000000000000000000000000000000000000000000000000000000000000100073eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001")),
+    ),
+    // correct proof - quadratic polynomial, z = r - 1
+    (
+        &hex!("01ad5c81cb0c297eb24fe3f2cc45e24b362c7e1837a36cda4ccbf8e88a5787c173eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000000555051c0d08bd0b355004f1750a4024f723d692d822c7f29167fe1489827396fb42d0ed7daf82f3e765803bddd3478fbfee55a512f6c0ffd16bb336861c5fee3d1b0b9f611da07f7c8e1ef0ddb498577902115f586e2376c64c2c7a9f7ade2fe5fe432a450d7a33e1ee9fb84d456e2b36d89af45e2b4ce57d395c5931cfd14d4"),
+        Ok(hex!("000000000000000000000000000000000000000000000000000000000000100073eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001")),
+    ),
+    // correct proof - cubic polynomial, random z
+    (
+        &hex!("0194b95358708dc668aeec6042a967903fdf93531b5e201f57a621085e8aaffe148cf60e716e77abff85621960c0510ff993f8bf44584c8dce20c9e43d1eece604b323b91221f6e9b3d4370b7d7ca44cd5baf4f0f0ff2af41d240383cc26e9998a7ac69ab001d6228ba1a10f2c3d11dc67905ddc07803f5cef102d0a110f08aea02fa125f38f19cf9c108a92ca466cb297dd91af528cbc64a73e52e8268ee1d788ddd81126ad3200b7b8c5ea16b036850d4bc2278fbe6efdccf8c9ed3805019f"),
+        Ok(hex!("000000000000000000000000000000000000000000000000000000000000100073eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001")),
+    ),
+    // correct proof - linear polynomial, random z
+    (
+        &hex!("01a71ba4c1bdd8b992fa482c1942a0442efa6fc11ef998ade318d16744cf2adc148cf60e716e77abff85621960c0510ff993f8bf44584c8dce20c9e43d1eece60cc6440c81a01ac4a50bb15ce42a9a7d8be0f421caf143bbf77f95888315e324b1bd3a7a511475ba22030110a84e1c7f94314c0e0f38bc43506ce883e0548eb657665990f6482e811ffd9dbf3371632ab9066bebc41ec24aa4b148c7ce03d0fdb81ed6798c0fc716a62b5cb1f9b328974d983d3a1bfa67a48d000e89b4416dcd"),
+        Ok(hex!("000000000000000000000000000000000000000000000000000000000000100073eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001")),
+    ),
+    // correct proof, point at infinity for the zero polynomial
+    (
+        &hex!("010657f37554c781402a22917dee2f75def7ab966d7b770905398eba3c444014148cf60e716e77abff85621960c0510ff993f8bf44584c8dce20c9e43d1eece60000000000000000000000000000000000000000000000000000000000000000c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+        Ok(hex!("000000000000000000000000000000000000000000000000000000000000100073eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001")),
+    ),
+    // correct proof, point at infinity for the twos polynomial
+    (
+        &hex!("01cf45213dd7b4716864d378f3c6d861467987e4d94b7f79a1f814a697e38637148cf60e716e77abff85621960c0510ff993f8bf44584c8dce20c9e43d1eece60000000000000000000000000000000000000000000000000000000000000002a572cbea904d67468808c8eb50a9450c9721db309128012543902d0ac358a62ae28f75bb8f1c7c42c39a8c5529bf0f4ec00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+        Ok(hex!("000000000000000000000000000000000000000000000000000000000000100073eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001")),
+    ),
+    // incorrect proof - proof for another point
+    (
+        &hex!("0194b95358708dc668aeec6042a967903fdf93531b5e201f57a621085e8aaffe148cf60e716e77abff85621960c0510ff993f8bf44584c8dce20c9e43d1eece604b323b91221f6e9b3d4370b7d7ca44cd5baf4f0f0ff2af41d240383cc26e9998a7ac69ab001d6228ba1a10f2c3d11dc67905ddc07803f5cef102d0a110f08aea02fa125f38f19cf9c108a92ca466cb28092d4aa191de46957a39cd02ba03d9e634fd0f627f7371ced2de469e60cf195d76cc22a2a2cb362c5a1edcee685099e"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobVerifyKzgProofFailed)),
+    ),
+    // incorrect proof - wrong evaluation
+    (
+        &hex!("0194b95358708dc668aeec6042a967903fdf93531b5e201f57a621085e8aaffe148cf60e716e77abff85621960c0510ff993f8bf44584c8dce20c9e43d1eece604b323b91221f6e9b3d4370b7d7ca44cd5baf4f0f0ff2af41d240383cc26e99a8a7ac69ab001d6228ba1a10f2c3d11dc67905ddc07803f5cef102d0a110f08aea02fa125f38f19cf9c108a92ca466cb297dd91af528cbc64a73e52e8268ee1d788ddd81126ad3200b7b8c5ea16b036850d4bc2278fbe6efdccf8c9ed3805019f"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobVerifyKzgProofFailed)),
+    ),
+    // incorrect proof - point at infinity for a non-constant polynomial
+    (
+        &hex!("0194b95358708dc668aeec6042a967903fdf93531b5e201f57a621085e8aaffe148cf60e716e77abff85621960c0510ff993f8bf44584c8dce20c9e43d1eece604b323b91221f6e9b3d4370b7d7ca44cd5baf4f0f0ff2af41d240383cc26e9998a7ac69ab001d6228ba1a10f2c3d11dc67905ddc07803f5cef102d0a110f08aea02fa125f38f19cf9c108a92ca466cb2c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobVerifyKzgProofFailed)),
+    ),
+    // incorrect proof - generator as proof
+    (
+        &hex!("0194b95358708dc668aeec6042a967903fdf93531b5e201f57a621085e8aaffe148cf60e716e77abff85621960c0510ff993f8bf44584c8dce20c9e43d1eece604b323b91221f6e9b3d4370b7d7ca44cd5baf4f0f0ff2af41d240383cc26e9998a7ac69ab001d6228ba1a10f2c3d11dc67905ddc07803f5cef102d0a110f08aea02fa125f38f19cf9c108a92ca466cb297f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobVerifyKzgProofFailed)),
+    ),
+    // incorrect proof - negated proof
+    (
+        &hex!("0194b95358708dc668aeec6042a967903fdf93531b5e201f57a621085e8aaffe148cf60e716e77abff85621960c0510ff993f8bf44584c8dce20c9e43d1eece604b323b91221f6e9b3d4370b7d7ca44cd5baf4f0f0ff2af41d240383cc26e9998a7ac69ab001d6228ba1a10f2c3d11dc67905ddc07803f5cef102d0a110f08aea02fa125f38f19cf9c108a92ca466cb2b7dd91af528cbc64a73e52e8268ee1d788ddd81126ad3200b7b8c5ea16b036850d4bc2278fbe6efdccf8c9ed3805019f"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobVerifyKzgProofFailed)),
+    ),
+    // invalid commitment - point not in G1
+    (
+        &hex!("016564752c546f453adeb98716f70a1167a34ffcc8aa605e2f3b0e0dbd8804f4148cf60e716e77abff85621960c0510ff993f8bf44584c8dce20c9e43d1eece604b323b91221f6e9b3d4370b7d7ca44cd5baf4f0f0ff2af41d240383cc26e9998123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef97dd91af528cbc64a73e52e8268ee1d788ddd81126ad3200b7b8c5ea16b036850d4bc2278fbe6efdccf8c9ed3805019f"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobVerifyKzgProofFailed)),
+    ),
+    // invalid commitment - point not on curve
+    (
+        &hex!("018b4962e42a010067618c230986810f6b2e12191db0762782c42bcf5462ebbc148cf60e716e77abff85621960c0510ff993f8bf44584c8dce20c9e43d1eece604b323b91221f6e9b3d4370b7d7ca44cd5baf4f0f0ff2af41d240383cc26e9998123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcde097dd91af528cbc64a73e52e8268ee1d788ddd81126ad3200b7b8c5ea16b036850d4bc2278fbe6efdccf8c9ed3805019f"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobVerifyKzgProofFailed)),
+    ),
+    // invalid commitment - x coordinate equal to p
+    (
+        &hex!("01b6c684e68711f333296c5dff88ecfb75b2f63aebb01496ca5235179cc8637c148cf60e716e77abff85621960c0510ff993f8bf44584c8dce20c9e43d1eece604b323b91221f6e9b3d4370b7d7ca44cd5baf4f0f0ff2af41d240383cc26e9999a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab97dd91af528cbc64a73e52e8268ee1d788ddd81126ad3200b7b8c5ea16b036850d4bc2278fbe6efdccf8c9ed3805019f"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobVerifyKzgProofFailed)),
+    ),
+    // invalid commitment - compression flag cleared
+    (
+        &hex!("010fbdfce3bc5c307dca54a0e1f5fc793e99783020027eceebff29d0aebe2949148cf60e716e77abff85621960c0510ff993f8bf44584c8dce20c9e43d1eece604b323b91221f6e9b3d4370b7d7ca44cd5baf4f0f0ff2af41d240383cc26e9990a7ac69ab001d6228ba1a10f2c3d11dc67905ddc07803f5cef102d0a110f08aea02fa125f38f19cf9c108a92ca466cb297dd91af528cbc64a73e52e8268ee1d788ddd81126ad3200b7b8c5ea16b036850d4bc2278fbe6efdccf8c9ed3805019f"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobVerifyKzgProofFailed)),
+    ),
+    // invalid commitment - infinity with sign flag set
+    (
+        &hex!("01d7b0f53eb74c51dffb427e2ead7fad7ea36e113aec7e9267c1502223c6a5c7148cf60e716e77abff85621960c0510ff993f8bf44584c8dce20c9e43d1eece604b323b91221f6e9b3d4370b7d7ca44cd5baf4f0f0ff2af41d240383cc26e999e0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000097dd91af528cbc64a73e52e8268ee1d788ddd81126ad3200b7b8c5ea16b036850d4bc2278fbe6efdccf8c9ed3805019f"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobVerifyKzgProofFailed)),
+    ),
+    // invalid commitment - infinity with nonzero x
+    (
+        &hex!("01cd96302cdd1f4303e5bffe63bdd98efd9de8509b7116f9a3e330e11ef65d62148cf60e716e77abff85621960c0510ff993f8bf44584c8dce20c9e43d1eece604b323b91221f6e9b3d4370b7d7ca44cd5baf4f0f0ff2af41d240383cc26e999c0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000197dd91af528cbc64a73e52e8268ee1d788ddd81126ad3200b7b8c5ea16b036850d4bc2278fbe6efdccf8c9ed3805019f"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobVerifyKzgProofFailed)),
+    ),
+    // invalid proof - point not in G1
+    (
+        &hex!("0194b95358708dc668aeec6042a967903fdf93531b5e201f57a621085e8aaffe148cf60e716e77abff85621960c0510ff993f8bf44584c8dce20c9e43d1eece604b323b91221f6e9b3d4370b7d7ca44cd5baf4f0f0ff2af41d240383cc26e9998a7ac69ab001d6228ba1a10f2c3d11dc67905ddc07803f5cef102d0a110f08aea02fa125f38f19cf9c108a92ca466cb28123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobVerifyKzgProofFailed)),
+    ),
+    // invalid proof - point not on curve
+    (
+        &hex!("0194b95358708dc668aeec6042a967903fdf93531b5e201f57a621085e8aaffe148cf60e716e77abff85621960c0510ff993f8bf44584c8dce20c9e43d1eece604b323b91221f6e9b3d4370b7d7ca44cd5baf4f0f0ff2af41d240383cc26e9998a7ac69ab001d6228ba1a10f2c3d11dc67905ddc07803f5cef102d0a110f08aea02fa125f38f19cf9c108a92ca466cb28123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcde0"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobVerifyKzgProofFailed)),
+    ),
+    // invalid proof - x coordinate equal to p
+    (
+        &hex!("0194b95358708dc668aeec6042a967903fdf93531b5e201f57a621085e8aaffe148cf60e716e77abff85621960c0510ff993f8bf44584c8dce20c9e43d1eece604b323b91221f6e9b3d4370b7d7ca44cd5baf4f0f0ff2af41d240383cc26e9998a7ac69ab001d6228ba1a10f2c3d11dc67905ddc07803f5cef102d0a110f08aea02fa125f38f19cf9c108a92ca466cb29a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobVerifyKzgProofFailed)),
+    ),
+    // invalid proof - compression flag cleared
+    (
+        &hex!("0194b95358708dc668aeec6042a967903fdf93531b5e201f57a621085e8aaffe148cf60e716e77abff85621960c0510ff993f8bf44584c8dce20c9e43d1eece604b323b91221f6e9b3d4370b7d7ca44cd5baf4f0f0ff2af41d240383cc26e9998a7ac69ab001d6228ba1a10f2c3d11dc67905ddc07803f5cef102d0a110f08aea02fa125f38f19cf9c108a92ca466cb20a7ac69ab001d6228ba1a10f2c3d11dc67905ddc07803f5cef102d0a110f08aea02fa125f38f19cf9c108a92ca466cb2"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobVerifyKzgProofFailed)),
+    ),
+    // invalid proof - infinity with sign flag set
+    (
+        &hex!("0194b95358708dc668aeec6042a967903fdf93531b5e201f57a621085e8aaffe148cf60e716e77abff85621960c0510ff993f8bf44584c8dce20c9e43d1eece604b323b91221f6e9b3d4370b7d7ca44cd5baf4f0f0ff2af41d240383cc26e9998a7ac69ab001d6228ba1a10f2c3d11dc67905ddc07803f5cef102d0a110f08aea02fa125f38f19cf9c108a92ca466cb2e00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobVerifyKzgProofFailed)),
+    ),
+    // invalid proof - infinity with nonzero x
+    (
+        &hex!("0194b95358708dc668aeec6042a967903fdf93531b5e201f57a621085e8aaffe148cf60e716e77abff85621960c0510ff993f8bf44584c8dce20c9e43d1eece604b323b91221f6e9b3d4370b7d7ca44cd5baf4f0f0ff2af41d240383cc26e9998a7ac69ab001d6228ba1a10f2c3d11dc67905ddc07803f5cef102d0a110f08aea02fa125f38f19cf9c108a92ca466cb2c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobVerifyKzgProofFailed)),
+    ),
+    // invalid z - r
+    (
+        &hex!("0194b95358708dc668aeec6042a967903fdf93531b5e201f57a621085e8aaffe73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff0000000104b323b91221f6e9b3d4370b7d7ca44cd5baf4f0f0ff2af41d240383cc26e9998a7ac69ab001d6228ba1a10f2c3d11dc67905ddc07803f5cef102d0a110f08aea02fa125f38f19cf9c108a92ca466cb297dd91af528cbc64a73e52e8268ee1d788ddd81126ad3200b7b8c5ea16b036850d4bc2278fbe6efdccf8c9ed3805019f"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobVerifyKzgProofFailed)),
+    ),
+    // invalid z - r + 1
+    (
+        &hex!("0194b95358708dc668aeec6042a967903fdf93531b5e201f57a621085e8aaffe73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff0000000204b323b91221f6e9b3d4370b7d7ca44cd5baf4f0f0ff2af41d240383cc26e9998a7ac69ab001d6228ba1a10f2c3d11dc67905ddc07803f5cef102d0a110f08aea02fa125f38f19cf9c108a92ca466cb297dd91af528cbc64a73e52e8268ee1d788ddd81126ad3200b7b8c5ea16b036850d4bc2278fbe6efdccf8c9ed3805019f"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobVerifyKzgProofFailed)),
+    ),
+    // invalid z - 2^256 - 1
+    (
+        &hex!("0194b95358708dc668aeec6042a967903fdf93531b5e201f57a621085e8aaffeffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff04b323b91221f6e9b3d4370b7d7ca44cd5baf4f0f0ff2af41d240383cc26e9998a7ac69ab001d6228ba1a10f2c3d11dc67905ddc07803f5cef102d0a110f08aea02fa125f38f19cf9c108a92ca466cb297dd91af528cbc64a73e52e8268ee1d788ddd81126ad3200b7b8c5ea16b036850d4bc2278fbe6efdccf8c9ed3805019f"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobVerifyKzgProofFailed)),
+    ),
+    // invalid y - r
+    (
+        &hex!("0194b95358708dc668aeec6042a967903fdf93531b5e201f57a621085e8aaffe148cf60e716e77abff85621960c0510ff993f8bf44584c8dce20c9e43d1eece673eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff000000018a7ac69ab001d6228ba1a10f2c3d11dc67905ddc07803f5cef102d0a110f08aea02fa125f38f19cf9c108a92ca466cb297dd91af528cbc64a73e52e8268ee1d788ddd81126ad3200b7b8c5ea16b036850d4bc2278fbe6efdccf8c9ed3805019f"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobVerifyKzgProofFailed)),
+    ),
+    // invalid y - r + 1
+    (
+        &hex!("0194b95358708dc668aeec6042a967903fdf93531b5e201f57a621085e8aaffe148cf60e716e77abff85621960c0510ff993f8bf44584c8dce20c9e43d1eece673eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff000000028a7ac69ab001d6228ba1a10f2c3d11dc67905ddc07803f5cef102d0a110f08aea02fa125f38f19cf9c108a92ca466cb297dd91af528cbc64a73e52e8268ee1d788ddd81126ad3200b7b8c5ea16b036850d4bc2278fbe6efdccf8c9ed3805019f"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobVerifyKzgProofFailed)),
+    ),
+    // invalid y - 2^256 - 1
+    (
+        &hex!("0194b95358708dc668aeec6042a967903fdf93531b5e201f57a621085e8aaffe148cf60e716e77abff85621960c0510ff993f8bf44584c8dce20c9e43d1eece6ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff8a7ac69ab001d6228ba1a10f2c3d11dc67905ddc07803f5cef102d0a110f08aea02fa125f38f19cf9c108a92ca466cb297dd91af528cbc64a73e52e8268ee1d788ddd81126ad3200b7b8c5ea16b036850d4bc2278fbe6efdccf8c9ed3805019f"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobVerifyKzgProofFailed)),
+    ),
+    // invalid y - r for the zero polynomial
+    (
+        &hex!("010657f37554c781402a22917dee2f75def7ab966d7b770905398eba3c444014148cf60e716e77abff85621960c0510ff993f8bf44584c8dce20c9e43d1eece673eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobVerifyKzgProofFailed)),
+    ),
+];
+
+/// Vector of test cases for the input checks done before proof verification.
+/// Each test case consists of (input_bytes, expected_error).
+/// Derived from the first valid input above, whose proof still verifies for its commitment.
+pub(crate) const KZG_INPUT_VALIDATION_TEST_CASES: &[KzgPointEvaluationTestCase] = &[
+    // 191 bytes - last proof byte dropped
+    (
+        &hex!("01e798154708fe7789429634053cbf9f99b619f9f084048927333fce637f549b564c0a11a0f704f4fc3e8acfe0f8245f0ad1347b378fbf96e206da11a5d3630624d25032e67a7e6a4910df5834b8fe70e6bcfeeac0352434196bdf4b2485d5a18f59a8d2a1a625a17f3fea0fe5eb8c896db3764f3185481bc22f91b4aaffcca25f26936857bc3a7c2539ea8ec3a952b7873033e038326e87ed3e1276fd140253fa08e9fc25fb2d9a98527fc22a2c9612fbeafdad446cbc7bcdbdcd780af2c1"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobInvalidInputLength)),
+    ),
+    // 193 bytes - trailing zero byte
+    (
+        &hex!("01e798154708fe7789429634053cbf9f99b619f9f084048927333fce637f549b564c0a11a0f704f4fc3e8acfe0f8245f0ad1347b378fbf96e206da11a5d3630624d25032e67a7e6a4910df5834b8fe70e6bcfeeac0352434196bdf4b2485d5a18f59a8d2a1a625a17f3fea0fe5eb8c896db3764f3185481bc22f91b4aaffcca25f26936857bc3a7c2539ea8ec3a952b7873033e038326e87ed3e1276fd140253fa08e9fc25fb2d9a98527fc22a2c9612fbeafdad446cbc7bcdbdcd780af2c16a00"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobInvalidInputLength)),
+    ),
+    // empty input
+    (
+        &hex!(""),
+        Err(PrecompileErrors::Error(PrecompileError::BlobInvalidInputLength)),
+    ),
+    // version byte 0x00
+    (
+        &hex!("00e798154708fe7789429634053cbf9f99b619f9f084048927333fce637f549b564c0a11a0f704f4fc3e8acfe0f8245f0ad1347b378fbf96e206da11a5d3630624d25032e67a7e6a4910df5834b8fe70e6bcfeeac0352434196bdf4b2485d5a18f59a8d2a1a625a17f3fea0fe5eb8c896db3764f3185481bc22f91b4aaffcca25f26936857bc3a7c2539ea8ec3a952b7873033e038326e87ed3e1276fd140253fa08e9fc25fb2d9a98527fc22a2c9612fbeafdad446cbc7bcdbdcd780af2c16a"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobMismatchedVersion)),
+    ),
+    // version byte 0x02
+    (
+        &hex!("02e798154708fe7789429634053cbf9f99b619f9f084048927333fce637f549b564c0a11a0f704f4fc3e8acfe0f8245f0ad1347b378fbf96e206da11a5d3630624d25032e67a7e6a4910df5834b8fe70e6bcfeeac0352434196bdf4b2485d5a18f59a8d2a1a625a17f3fea0fe5eb8c896db3764f3185481bc22f91b4aaffcca25f26936857bc3a7c2539ea8ec3a952b7873033e038326e87ed3e1276fd140253fa08e9fc25fb2d9a98527fc22a2c9612fbeafdad446cbc7bcdbdcd780af2c16a"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobMismatchedVersion)),
+    ),
+    // unversioned sha256 of the commitment
+    (
+        &hex!("f7e798154708fe7789429634053cbf9f99b619f9f084048927333fce637f549b564c0a11a0f704f4fc3e8acfe0f8245f0ad1347b378fbf96e206da11a5d3630624d25032e67a7e6a4910df5834b8fe70e6bcfeeac0352434196bdf4b2485d5a18f59a8d2a1a625a17f3fea0fe5eb8c896db3764f3185481bc22f91b4aaffcca25f26936857bc3a7c2539ea8ec3a952b7873033e038326e87ed3e1276fd140253fa08e9fc25fb2d9a98527fc22a2c9612fbeafdad446cbc7bcdbdcd780af2c16a"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobMismatchedVersion)),
+    ),
+    // last byte of the versioned hash flipped
+    (
+        &hex!("01e798154708fe7789429634053cbf9f99b619f9f084048927333fce637f549a564c0a11a0f704f4fc3e8acfe0f8245f0ad1347b378fbf96e206da11a5d3630624d25032e67a7e6a4910df5834b8fe70e6bcfeeac0352434196bdf4b2485d5a18f59a8d2a1a625a17f3fea0fe5eb8c896db3764f3185481bc22f91b4aaffcca25f26936857bc3a7c2539ea8ec3a952b7873033e038326e87ed3e1276fd140253fa08e9fc25fb2d9a98527fc22a2c9612fbeafdad446cbc7bcdbdcd780af2c16a"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobMismatchedVersion)),
+    ),
+    // versioned hash of the infinity commitment
+    (
+        &hex!("010657f37554c781402a22917dee2f75def7ab966d7b770905398eba3c444014564c0a11a0f704f4fc3e8acfe0f8245f0ad1347b378fbf96e206da11a5d3630624d25032e67a7e6a4910df5834b8fe70e6bcfeeac0352434196bdf4b2485d5a18f59a8d2a1a625a17f3fea0fe5eb8c896db3764f3185481bc22f91b4aaffcca25f26936857bc3a7c2539ea8ec3a952b7873033e038326e87ed3e1276fd140253fa08e9fc25fb2d9a98527fc22a2c9612fbeafdad446cbc7bcdbdcd780af2c16a"),
+        Err(PrecompileErrors::Error(PrecompileError::BlobMismatchedVersion)),
+    ),
+];
+
+/// Gas charged by the point evaluation precompile
+pub(crate) const KZG_POINT_EVALUATION_GAS_COST: u64 = 50_000;
+
 /// Run all KZG point evaluation precompile test cases
 pub fn run_kzg_point_evaluation_tests() {
-    for (input, expected) in KZG_POINT_EVALUATION_TEST_CASES {
+    for (input, expected) in KZG_POINT_EVALUATION_TEST_CASES
+        .iter()
+        .chain(KZG_SYNTHETIC_PROOF_TEST_CASES)
+        .chain(KZG_INPUT_VALIDATION_TEST_CASES)
+    {
         let input = Bytes::from_static(input);
         let result = run(&input, u64::MAX, &Env::default());
 
         match (result, expected) {
             (Ok(output), Ok(expected_bytes)) => {
+                assert_eq!(output.gas_used, KZG_POINT_EVALUATION_GAS_COST);
                 assert_eq!(output.bytes.as_ref(), expected_bytes.as_slice());
             }
             (Err(error), Err(expected_error)) => {
@@ -46,4 +275,67 @@ pub fn run_kzg_point_evaluation_tests() {
             }
         }
     }
+
+    // the gas limit is checked before the input is parsed
+    let (valid_input, _) = KZG_POINT_EVALUATION_TEST_CASES[0];
+    let input = Bytes::from_static(valid_input);
+    assert_eq!(
+        run(&input, KZG_POINT_EVALUATION_GAS_COST - 1, &Env::default()).unwrap_err(),
+        PrecompileErrors::Error(PrecompileError::OutOfGas)
+    );
+    assert_eq!(
+        run(&Bytes::new(), 0, &Env::default()).unwrap_err(),
+        PrecompileErrors::Error(PrecompileError::OutOfGas)
+    );
+    assert!(run(&input, KZG_POINT_EVALUATION_GAS_COST, &Env::default()).is_ok());
+}
+
+/// Run point evaluation vectors against an alternate trusted setup read from stdin,
+/// see `scripts/kzg_devnet_setup.py`.
+/// The input stream holds the concatenated 48-byte G1 Lagrange points, the concatenated 96-byte
+/// G2 monomial points, a 4-byte little-endian case count, then for each case the 192-byte input
+/// and one byte that is 1 if the proof is valid under the alternate setup.
+/// Valid inputs must fail under the default mainnet setup, so the setup selected in `Env` is the one used.
+#[cfg(feature = "kzg-custom-setup")]
+pub fn run_kzg_custom_setup_tests() {
+    let g1_points: Vec<[u8; 48]> = openvm::io::read_vec()
+        .chunks_exact(48)
+        .map(|point| point.try_into().unwrap())
+        .collect();
+    let g2_points: Vec<[u8; 96]> = openvm::io::read_vec()
+        .chunks_exact(96)
+        .map(|point| point.try_into().unwrap())
+        .collect();
+    let settings =
+        KzgSettings::load_trusted_setup(&g1_points, &g2_points).expect("invalid trusted setup");
+
+    let mut custom_env = Env::default();
+    custom_env.cfg.kzg_settings = EnvKzgSettings::Custom(Arc::new(settings));
+    let default_env = Env::default();
+    let failed = PrecompileErrors::Error(PrecompileError::BlobVerifyKzgProofFailed);
+
+    let count = u32::from_le_bytes(openvm::io::read_vec().try_into().unwrap());
+    for _ in 0..count {
+        let input = Bytes::from(openvm::io::read_vec());
+        let valid = openvm::io::read_vec() == [1];
+
+        let result = run(&input, u64::MAX, &custom_env);
+        if valid {
+            let output = result.unwrap();
+            assert_eq!(
+                output.bytes.as_ref(),
+                KZG_POINT_EVALUATION_TEST_CASES[0].1.as_ref().unwrap()
+            );
+            assert_eq!(run(&input, u64::MAX, &default_env).unwrap_err(), failed);
+        } else {
+            assert_eq!(result.unwrap_err(), failed);
+        }
+    }
+
+    // mainnet proofs do not verify under the alternate setup
+    let (mainnet_input, _) = KZG_POINT_EVALUATION_TEST_CASES[0];
+    assert_eq!(
+        run(&Bytes::from_static(mainnet_input), u64::MAX, &custom_env).unwrap_err(),
+        failed
+    );
 }
@@ -1,10 +1,28 @@
+mod bigint;
 mod bn128;
+mod cycle_tracker;
 mod ecrecover;
+mod fp2;
+#[cfg(feature = "groth16")]
+mod groth16;
 mod keccak256;
+#[cfg(feature = "kzg-blobs")]
+mod kzg_blob;
 mod kzg_point_evaluation;
 mod modexp;
+mod modular;
+mod pairing;
+mod registry;
+mod rsa;
+#[cfg(feature = "rsa-wycheproof")]
+mod rsa_wycheproof;
 mod secp256r1;
 mod sha256;
+#[cfg(feature = "precompile-trace")]
+mod trace;
+mod webauthn;
+#[cfg(feature = "webauthn-fixtures")]
+mod webauthn_fixtures;
 
 use openvm_algebra_complex_macros::complex_init;
 use openvm_algebra_guest::moduli_macros::moduli_init;
@@ -56,24 +74,69 @@ fn main() {
         setup_all_curves();
     }
 
+    // modular arithmetic tests
+    modular::run_modular_tests();
+
+    // 256-bit integer tests
+    bigint::run_bigint_tests();
+
+    // fp2 extension field tests
+    fp2::run_fp2_tests();
+
+    // pairing check tests
+    pairing::run_pairing_tests();
+
     // hash function tests
     keccak256::run_keccak_tests();
+    keccak256::run_keccak_streaming_tests();
+    keccak256::run_keccak_permutation_tests();
     sha256::run_sha256_tests();
+    #[cfg(feature = "sha256-monte-carlo")]
+    sha256::run_sha256_monte_carlo_test();
 
     // modexp tests
     modexp::run_modexp_tests();
+    modexp::run_modexp_backend_tests();
+    #[cfg(feature = "modexp-backends")]
+    modexp::run_modexp_backend_comparison();
+
+    // rsa signatures on modexp
+    rsa::run_rsa_tests();
+    #[cfg(feature = "rsa-wycheproof")]
+    rsa_wycheproof::run_rsa_wycheproof_tests();
 
     // bn254 (alt bn128) tests
     bn128::run_ecadd_tests();
     bn128::run_ecmul_tests();
     bn128::run_ecpairing_tests();
+    #[cfg(feature = "pairing-scaling")]
+    bn128::run_ecpairing_scaling_tests();
+    #[cfg(feature = "groth16")]
+    groth16::run_groth16_tests();
 
     // secp256k1
     ecrecover::run_ecrecover_tests();
+    ecrecover::run_ecrecover_matrix_tests();
+    ecrecover::run_ecrecover_round_trip_tests();
 
     // secp256r1 (p256) verify
     secp256r1::run_p256_tests();
+    webauthn::run_webauthn_tests();
+    #[cfg(feature = "webauthn-fixtures")]
+    webauthn_fixtures::run_webauthn_fixture_tests();
 
     // kzg point evaluation
     kzg_point_evaluation::run_kzg_point_evaluation_tests();
+    #[cfg(feature = "kzg-blobs")]
+    kzg_blob::run_kzg_blob_tests();
+    #[cfg(feature = "kzg-custom-setup")]
+    kzg_point_evaluation::run_kzg_custom_setup_tests();
+
+    // precompiles resolved by address from the registry of each spec
+    registry::run_registry_tests();
+    registry::run_availability_tests();
+
+    // recorded precompile calls
+    #[cfg(feature = "precompile-trace")]
+    trace::run_trace_tests();
 }
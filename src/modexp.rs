@@ -1,10 +1,23 @@
 use hex_literal::hex;
-use revm_precompile::modexp::run_inner;
+use openvm_algebra_guest::IntMod;
+use openvm_bigint_guest::U256;
+use openvm_ecc_guest::{
+    k256::{Secp256k1Coord, Secp256k1Scalar},
+    p256::{P256Coord, P256Scalar},
+};
+use openvm_pairing_guest::{
+    bls12_381::{Fp as Bls12_381Fp, Scalar as Bls12_381Scalar},
+    bn254::{Fp as Bn254Fp, Scalar as Bn254Scalar},
+};
+use revm_precompile::{modexp::run_inner, PrecompileOutput, PrecompileResult};
+
+#[cfg(feature = "modexp-backends")]
+use crate::cycle_tracker;
 
 /// Vector of test cases for modular exponentiation.
 /// Source: https://github.com/ethereum/go-ethereum/blob/master/core/vm/testdata/precompiles/modexp.json
 /// Each test case consists of (input_bytes, expected_result_bytes).
-const MODEXP_TEST_CASES: &[(&[u8], &[u8])] = &[
+pub(crate) const MODEXP_TEST_CASES: &[(&[u8], &[u8])] = &[
     // eip_example1
     (
         &hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000002003fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2efffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f"),
@@ -108,10 +121,567 @@ const MODEXP_TEST_CASES: &[(&[u8], &[u8])] = &[
     ),
 ];
 
-/// Run all modular exponentiation test cases
+/// Vector of EIP-198 edge cases for modular exponentiation, expected results computed in Python.
+/// Lengths are read from three 32-byte words and input past its end reads as zero. A zero modulus
+/// gives zero, and zero base and modulus lengths give no output whatever the exponent length.
+/// Each test case consists of (input_bytes, expected_result_bytes).
+pub(crate) const MODEXP_EDGE_CASES: &[(&[u8], &[u8])] = &[
+    // mod_len 0, empty output
+    (
+        &hex!("0000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000000203"),
+        &hex!(""),
+    ),
+    // base_len 0, the base is zero
+    (
+        &hex!("0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000010305"),
+        &hex!("00"),
+    ),
+    // base_len and exp_len 0, 0^0 = 1
+    (
+        &hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000105"),
+        &hex!("01"),
+    ),
+    // exponent 0 with modulus 1
+    (
+        &hex!("000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000001020001"),
+        &hex!("00"),
+    ),
+    // exponent 0 with a 32-byte modulus 1
+    (
+        &hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000002002000000000000000000000000000000000000000000000000000000000000000001"),
+        &hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    ),
+    // exponent 0 with modulus 13
+    (
+        &hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000102000d"),
+        &hex!("01"),
+    ),
+    // modulus 0
+    (
+        &hex!("000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000001020300"),
+        &hex!("00"),
+    ),
+    // 32-byte modulus 0
+    (
+        &hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000002002030000000000000000000000000000000000000000000000000000000000000000"),
+        &hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    ),
+    // modulus truncated, 0x01 is padded to 0x0100
+    (
+        &hex!("000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002020301"),
+        &hex!("0008"),
+    ),
+    // modulus missing, padded to zero
+    (
+        &hex!("0000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000010203"),
+        &hex!("00"),
+    ),
+    // exponent and modulus missing
+    (
+        &hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000102"),
+        &hex!("00"),
+    ),
+    // mod_len missing from the header
+    (
+        &hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000001"),
+        &hex!(""),
+    ),
+    // empty input
+    (
+        &hex!(""),
+        &hex!(""),
+    ),
+    // exp_len 40 with leading zeros
+    (
+        &hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000028000000000000000000000000000000000000000000000000000000000000000102000000000000000000000000000000000000000000000000000000000000000000000000000000030d"),
+        &hex!("08"),
+    ),
+    // exp_len 64, exponent 2^256 + 1
+    (
+        &hex!("000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000001030000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000164"),
+        &hex!("3f"),
+    ),
+    // 256-byte exponent of 0xff with modulus 0
+    (
+        &hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000000102ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff00"),
+        &hex!("00"),
+    ),
+    // exp_len 2^64 - 1 with base_len and mod_len 0
+    (
+        &hex!("0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000ffffffffffffffff0000000000000000000000000000000000000000000000000000000000000000"),
+        &hex!(""),
+    ),
+    // exp_len 2^256 - 1 with base_len and mod_len 0
+    (
+        &hex!("0000000000000000000000000000000000000000000000000000000000000000ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff0000000000000000000000000000000000000000000000000000000000000000"),
+        &hex!(""),
+    ),
+];
+
+/// Vector of test cases whose modulus is one of the moduli in `moduli_init!`, so they run on the
+/// modular arithmetic chips in `accelerated_modexp`. Bases and exponents are bytes of sha512 digests
+/// of fixed labels, expected results computed in Python.
+/// Each test case consists of (input_bytes, expected_result_bytes).
+const MODEXP_INTRINSIC_TEST_CASES: &[(&[u8], &[u8])] = &[
+    // bn254 Fp, 32-byte base and exponent
+    (
+        &hex!("0000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000209603c745e8310be04ded28a9df9784c3977520575da45f35e742998fb2d3d77a097b4831d82795d36926e1c1055cf313996eaf9c4ed6d3340dab17976e4a950a30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47"),
+        &hex!("0c1281db3e170cd79baf3e19034fc91d5d202651c7cdc45724bdd977392b71d5"),
+    ),
+    // bn254 Fr, 32-byte base and exponent
+    (
+        &hex!("00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000002019b233a5661ec7643b3b1de1003a3054eb09214ca35a43f0a7d9576b4c39c805ca39c8a52c8c9c2a6e44498087f0866ed9a4fbbc28a2d04e2a89067aca849ee330644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001"),
+        &hex!("1b23bdef917a7f2ca23190718f4dc1d6b9b8f218366c7fb3f1a4d815afc7ae91"),
+    ),
+    // secp256k1 p, 32-byte base and exponent
+    (
+        &hex!("000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000020be14540127d678e27dc35a28b1e018958177c26d903f526fd3383c754ff189df0edd9e7c830cb58069cd5d247daf4f75e9341f6bc4191f1939168e95cc7c3550fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f"),
+        &hex!("23742c1cf04c7484d8bb05abd03b155b450e2f263db05b45c99f3cdbc6a6b30a"),
+    ),
+    // secp256k1 n, 32-byte base and exponent
+    (
+        &hex!("00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000002069d29ff97109d06967f4e2cbfa4886c9a4c726443db759d5450d8d4e830a728eb1517d13db09aca94e3b84f9948813a127cc5b28be2d9db08ebacc3b7ca430bafffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"),
+        &hex!("e5651e75afa1ce77a83449ae0836a431e6c19208af9b0f88e99d4293567beb75"),
+    ),
+    // p256 p, 32-byte base and exponent
+    (
+        &hex!("0000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000200bf4c4485a1870a4ddb6c30d3faca064236918dda8b537d11dba869155252066ac4f5d3e59ef810b748f4c9859aace8f1f1c43f14150e30ea58b541f86abba21ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"),
+        &hex!("e8dbdb5fd2ccad503186661d057981fe72c80e9fdef120b3173928ed1b5e4ade"),
+    ),
+    // p256 n, 32-byte base and exponent
+    (
+        &hex!("00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000002007ccb1a38fd282752acf00b940f4f64838db2813d261bd80a9212b25d4cac449c80e0bd1e8ce42b79e6d19d2d6fcd7055b2e532f349b14fde106994781e47d01ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"),
+        &hex!("38149241543896b3450e7a0554b2f9080ba0a1d207a426e7b9bbb0033d16fb90"),
+    ),
+    // bls12_381 Fp, 48-byte base and exponent
+    (
+        &hex!("00000000000000000000000000000000000000000000000000000000000000300000000000000000000000000000000000000000000000000000000000000030000000000000000000000000000000000000000000000000000000000000003082de79e7a470208152e35108e1d20d14d959c1fe3c47c6ea6871ba3be4562c7c01a4809bbee4a762a243bc0d60bdb994e305523bdc43546d9e8b240d8f443fbd5e30a3a9098bd91ee4378cffc9a9b4f93b07ac083dd729bdb6a44656a76ddd6e1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab"),
+        &hex!("0965d0b445344570e2cae0841a4cd5e061d1e94956177073b97cf71b18c6ab0513d15f633e386a760b6329c05b5529b5"),
+    ),
+    // bls12_381 Fr, 32-byte base and exponent
+    (
+        &hex!("0000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000201d805e03124b67792620af6c747291705476dcda5f0065d1930242fadc099f4daf2280b955b442bfc201bec50e6838019b8301e18c6a25981ad2c6d867c6523473eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001"),
+        &hex!("4ac142355532d66970f50822a8949eed743c170221a11901d33e13c3201ad5a0"),
+    ),
+    // bn254 Fp, base^(p - 2) is the inverse of the base
+    (
+        &hex!("000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000020d17e73c25caa701c3af40388f0a0ca409b7850f92351f894b3230f5e9bb9fd1930644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd4530644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47"),
+        &hex!("2d9b7aa5f4ee932c1720d1ea88b117e1e33e9d2f4f183488e429f5650da0392e"),
+    ),
+    // bn254 Fr given as a 64-byte modulus, 64-byte base
+    (
+        &hex!("0000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000402c4fdd93aaf07ad24963086b1fe7aa1fcc27e556c233b31cc4cd8d106647c332cac319c6b7ae054d9738ee0122816f57c372a642cdc8e3a5e6b13d60b44be3948bea7b03f89871382502aadca856d205018f8fdfa15f3f624457c5b9438c4719000000000000000000000000000000000000000000000000000000000000000030644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001"),
+        &hex!("00000000000000000000000000000000000000000000000000000000000000002a16adca0bfa3215925b9b23aa5631a7aa71fe1d509350674ad400074fa5f31b"),
+    ),
+    // secp256k1 n, exponent 0
+    (
+        &hex!("00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002076206a55c0d0b8b1924189593e3ec982b9da787e1b65665e864deb5026923a22fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"),
+        &hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+    ),
+    // bls12_381 Fp, non-canonical base p + 1 squared
+    (
+        &hex!("0000000000000000000000000000000000000000000000000000000000000030000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000301a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaac021a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab"),
+        &hex!("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001"),
+    ),
+    // p256 p, base 0
+    (
+        &hex!("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000020fcda0d570dcaa2b6af9d1f84905673ef50a0408ea5283c30ff870ced9e7df6f2ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"),
+        &hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    ),
+    // bn254 Fp, exponent longer than 32 bytes
+    (
+        &hex!("000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000020c6a955d953f7604f3baa282d4cf03ac2f22ea61676bbfeb0ca28a29bfee3b28fd2b7e892f66835244e72e7766218a821d1b3cd318ca6536ff3daacb30c2a599b75e4db27db42e82ec6b3a0675eb962786d248524f865dff2fcc814ab8e11c6be30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47"),
+        &hex!("153fd7fd8b2ea0938f2f37c535ce99b21dd4b5113a5b09d288df35b98b47433d"),
+    ),
+];
+
+/// Run all modular exponentiation test cases, including the edge cases
 pub fn run_modexp_tests() {
-    for (input, expected) in MODEXP_TEST_CASES {
+    for (input, expected) in MODEXP_TEST_CASES.iter().chain(MODEXP_EDGE_CASES) {
         let outcome = run_inner(input, u64::MAX, 0, |_, _, _, _| 0).unwrap();
         assert_eq!(&outcome.bytes.as_ref(), expected);
     }
 }
+
+/// Run the modexp vectors that are cheap on `accelerated_modexp`: the `moduli_init!` vectors, the
+/// EIP-198 examples, nagydani-1 and the edge cases, checking results and backends.
+/// Vectors with a modulus in `moduli_init!` must run on its chip, and any other vector with an odd
+/// modulus on the bigint chip.
+pub fn run_modexp_backend_tests() {
+    for (index, (input, expected)) in MODEXP_INTRINSIC_TEST_CASES.iter().enumerate() {
+        let (result, backend) = accelerated_modexp(input);
+        assert_eq!(&result.unwrap().bytes.as_ref(), expected, "modexp #{index}");
+        assert_eq!(backend, ModexpBackend::Intrinsic, "modexp #{index} backend");
+    }
+
+    // the four EIP-198 examples and the three nagydani-1 vectors
+    for (index, (input, expected)) in MODEXP_TEST_CASES[..7]
+        .iter()
+        .chain(MODEXP_EDGE_CASES)
+        .enumerate()
+    {
+        let (result, backend) = accelerated_modexp(input);
+        assert_eq!(&result.unwrap().bytes.as_ref(), expected, "modexp #{index}");
+        if has_odd_modulus(input) {
+            assert_ne!(backend, ModexpBackend::Software, "modexp #{index} backend");
+        }
+    }
+    for (index, (input, _)) in MODEXP_TEST_CASES[4..7].iter().enumerate() {
+        assert_eq!(
+            accelerated_modexp(input).1,
+            ModexpBackend::Bigint,
+            "nagydani-1 #{index} backend"
+        );
+    }
+}
+
+/// Run every modexp vector on `run_inner` and on `accelerated_modexp`, checking both results,
+/// with cycle markers per vector and backend for comparing their cost.
+/// Vectors with a modulus in `moduli_init!` must run on its chip, and any other vector with an odd
+/// modulus on the bigint chip.
+#[cfg(feature = "modexp-backends")]
+pub fn run_modexp_backend_comparison() {
+    let intrinsic_cases = MODEXP_INTRINSIC_TEST_CASES.iter().map(|case| (case, true));
+    let other_cases = MODEXP_TEST_CASES
+        .iter()
+        .chain(MODEXP_EDGE_CASES)
+        .map(|case| (case, false));
+    for (index, ((input, expected), intrinsic)) in intrinsic_cases.chain(other_cases).enumerate() {
+        let label = format!("modexp #{index} software");
+        cycle_tracker::start(&label);
+        let software = run_inner(input, u64::MAX, 0, |_, _, _, _| 0).unwrap();
+        cycle_tracker::end(&label);
+
+        let label = format!("modexp #{index} accelerated");
+        cycle_tracker::start(&label);
+        let (accelerated, backend) = accelerated_modexp(input);
+        cycle_tracker::end(&label);
+
+        assert_eq!(
+            &software.bytes.as_ref(),
+            expected,
+            "modexp #{index} software"
+        );
+        assert_eq!(
+            &accelerated.unwrap().bytes.as_ref(),
+            expected,
+            "modexp #{index} accelerated"
+        );
+        if intrinsic {
+            assert_eq!(backend, ModexpBackend::Intrinsic, "modexp #{index} backend");
+        } else if has_odd_modulus(input) {
+            assert_ne!(backend, ModexpBackend::Software, "modexp #{index} backend");
+        }
+    }
+}
+
+/// Whether a modexp input has a nonzero odd modulus
+fn has_odd_modulus(input: &[u8]) -> bool {
+    modexp_operands(input)
+        .is_some_and(|(_, _, modulus, _)| modulus.last().is_some_and(|byte| byte & 1 == 1))
+}
+
+/// Backend an `accelerated_modexp` call ran on
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ModexpBackend {
+    /// square-and-multiply on the modular arithmetic chip of the modulus
+    Intrinsic,
+    /// Montgomery multiplication on the 256-bit bigint chip, for odd moduli of any size
+    Bigint,
+    /// revm's software bignum `run_inner`
+    Software,
+}
+
+/// modexp precompile backend using the openvm modular arithmetic and bigint chips, charging no gas.
+/// The modular arithmetic chips are configured per modulus rather than per size, so inputs whose
+/// modulus is one of the 256-bit moduli or the 384-bit bls12_381 coordinate field modulus in
+/// `moduli_init!` run on the chip of that modulus. Other odd moduli run Montgomery multiplication on
+/// the bigint chip, and even or zero moduli fall back to `run_inner`.
+fn accelerated_modexp(input: &[u8]) -> (PrecompileResult, ModexpBackend) {
+    let Some((base, exponent, modulus, mod_len)) = modexp_operands(input) else {
+        return (
+            run_inner(input, u64::MAX, 0, |_, _, _, _| 0),
+            ModexpBackend::Software,
+        );
+    };
+
+    let intrinsic = intrinsic_modexp::<Bn254Fp>(&base, &exponent, &modulus)
+        .or_else(|| intrinsic_modexp::<Bn254Scalar>(&base, &exponent, &modulus))
+        .or_else(|| intrinsic_modexp::<Secp256k1Coord>(&base, &exponent, &modulus))
+        .or_else(|| intrinsic_modexp::<Secp256k1Scalar>(&base, &exponent, &modulus))
+        .or_else(|| intrinsic_modexp::<P256Coord>(&base, &exponent, &modulus))
+        .or_else(|| intrinsic_modexp::<P256Scalar>(&base, &exponent, &modulus))
+        .or_else(|| intrinsic_modexp::<Bls12_381Fp>(&base, &exponent, &modulus))
+        .or_else(|| intrinsic_modexp::<Bls12_381Scalar>(&base, &exponent, &modulus));
+    let (result, backend) = match intrinsic {
+        Some(result) => (result, ModexpBackend::Intrinsic),
+        None => match bigint_modexp(&base, &exponent, &modulus) {
+            Some(result) => (result, ModexpBackend::Bigint),
+            None => {
+                return (
+                    run_inner(input, u64::MAX, 0, |_, _, _, _| 0),
+                    ModexpBackend::Software,
+                )
+            }
+        },
+    };
+
+    // the result is padded to the declared modulus length
+    let mut output = vec![0u8; mod_len];
+    output[mod_len - result.len()..].copy_from_slice(&result);
+    (Ok(PrecompileOutput::new(0, output.into())), backend)
+}
+
+/// Base, exponent, modulus without leading zeros, and modulus length of a modexp input.
+/// Inputs with no modulus or with lengths past the end of the input are left to `run_inner`.
+fn modexp_operands(input: &[u8]) -> Option<(Vec<u8>, Vec<u8>, Vec<u8>, usize)> {
+    let length = |offset| {
+        let word = padded(input, offset, 32);
+        let length = u64::from_be_bytes(word[24..].try_into().unwrap());
+        let fits = word[..24].iter().all(|&byte| byte == 0) && length <= input.len() as u64;
+        fits.then_some(length as usize)
+    };
+    let (base_len, exp_len, mod_len) = (length(0)?, length(32)?, length(64)?);
+    if mod_len == 0 {
+        return None;
+    }
+
+    let base = padded(input, 96, base_len);
+    let exponent = padded(input, 96 + base_len, exp_len);
+    let modulus = padded(input, 96 + base_len + exp_len, mod_len);
+    let leading_zeros = modulus.iter().take_while(|&&byte| byte == 0).count();
+    Some((base, exponent, modulus[leading_zeros..].to_vec(), mod_len))
+}
+
+/// `base^exponent mod F::MODULUS` with left-to-right square-and-multiply, as big-endian bytes of
+/// the limb count of `F`, or `None` if `modulus` is not the modulus of `F`
+fn intrinsic_modexp<F: IntMod>(base: &[u8], exponent: &[u8], modulus: &[u8]) -> Option<Vec<u8>> {
+    let modulus_be: Vec<u8> = F::MODULUS.as_ref().iter().rev().copied().collect();
+    if modulus != modulus_be {
+        return None;
+    }
+
+    // reduce a base of any length limb-sized chunk by chunk, with radix 2^(8 * NUM_LIMBS) mod p
+    let radix = F::from_be_bytes(&vec![0xff; F::NUM_LIMBS]) + F::ONE;
+    let padding = (F::NUM_LIMBS - base.len() % F::NUM_LIMBS) % F::NUM_LIMBS;
+    let base = [vec![0u8; padding], base.to_vec()].concat();
+    let mut reduced_base = F::ZERO;
+    for chunk in base.chunks(F::NUM_LIMBS) {
+        reduced_base = reduced_base * radix.clone() + F::from_be_bytes(chunk);
+    }
+
+    let mut result = F::ONE;
+    for bit in exponent_bits(exponent) {
+        result = result.clone() * result;
+        if bit {
+            result = result * reduced_base.clone();
+        }
+    }
+    // `from_be_bytes` accepts non-canonical values, so the product chain may not end reduced
+    result.assert_reduced();
+    Some(result.to_be_bytes().as_ref().to_vec())
+}
+
+/// `base^exponent mod modulus` with Montgomery multiplication on the bigint chip, as big-endian bytes
+/// of the length of `modulus`, or `None` if `modulus` is even or zero
+fn bigint_modexp(base: &[u8], exponent: &[u8], modulus: &[u8]) -> Option<Vec<u8>> {
+    if !modulus.last().is_some_and(|byte| byte & 1 == 1) {
+        return None;
+    }
+    if modulus == [1] {
+        return Some(vec![0]);
+    }
+    let montgomery = Montgomery::new(modulus);
+    let size = montgomery.modulus.len();
+
+    // reduce a base of any length chunk by chunk, with radix R: acc * R + chunk in Montgomery form
+    // is mul(acc * R, R^2) + mul(chunk, R^2)
+    let chunk_len = size * DIGIT_BYTES;
+    let padding = (chunk_len - base.len() % chunk_len) % chunk_len;
+    let base = [vec![0u8; padding], base.to_vec()].concat();
+    let mut reduced_base = vec![U256::ZERO; size];
+    for chunk in base.chunks(chunk_len) {
+        let shifted = montgomery.mul(&reduced_base, &montgomery.r_squared);
+        let chunk = montgomery.mul(&digits(chunk, size), &montgomery.r_squared);
+        reduced_base = montgomery.add(&shifted, &chunk);
+    }
+
+    let one = digits(&[1], size);
+    let mut result = montgomery.mul(&one, &montgomery.r_squared);
+    for bit in exponent_bits(exponent) {
+        result = montgomery.mul(&result, &result);
+        if bit {
+            result = montgomery.mul(&result, &reduced_base);
+        }
+    }
+    let result = digits_to_be_bytes(&montgomery.mul(&result, &one));
+    Some(result[result.len() - modulus.len()..].to_vec())
+}
+
+/// Bits of a big-endian exponent from the most significant one, without leading zeros
+fn exponent_bits(exponent: &[u8]) -> impl Iterator<Item = bool> + '_ {
+    let leading_zeros = exponent.iter().take_while(|&&byte| byte == 0).count();
+    exponent[leading_zeros..]
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |bit| (byte >> bit) & 1 == 1))
+}
+
+/// Bytes of a digit of the bigint backend. Digits are 128-bit values held in U256 words, so a
+/// product of two digits plus two more digits never overflows a word.
+const DIGIT_BYTES: usize = 16;
+
+/// Montgomery arithmetic modulo an odd modulus of little-endian digits, with R = 2^(128 * digits).
+/// Operands and results are little-endian digits below the modulus.
+struct Montgomery {
+    modulus: Vec<U256>,
+    /// -modulus^-1 mod 2^128
+    inverse: U256,
+    /// R^2 mod modulus
+    r_squared: Vec<U256>,
+    /// 2^128 - 1
+    mask: U256,
+    /// 128, the shift from a word to its high digit
+    shift: U256,
+}
+
+impl Montgomery {
+    fn new(modulus: &[u8]) -> Self {
+        let size = modulus.len().div_ceil(DIGIT_BYTES);
+        let mut montgomery = Self {
+            modulus: digits(modulus, size),
+            inverse: U256::ZERO,
+            r_squared: digits(&[1], size),
+            mask: U256::from_le_bytes(mask_le_bytes()),
+            shift: U256::from_u8(128),
+        };
+
+        // Newton iteration doubles the correct low bits of modulus^-1 from 3, since n * n = 1 mod 8
+        let n0 = montgomery.modulus[0].clone();
+        let two = U256::from_u8(2);
+        let mut inverse = n0.clone();
+        for _ in 0..6 {
+            let correction = montgomery.low(&(&two - &montgomery.low(&(&n0 * &inverse))));
+            inverse = montgomery.low(&(&inverse * &correction));
+        }
+        montgomery.inverse = montgomery.low(&(&U256::ZERO - &inverse));
+
+        // R^2 mod modulus by doubling 1, the modulus is above 1
+        for _ in 0..2 * 128 * size {
+            montgomery.r_squared = montgomery.add(&montgomery.r_squared, &montgomery.r_squared);
+        }
+        montgomery
+    }
+
+    /// `a * b * R^-1 mod modulus` with coarsely integrated operand scanning
+    fn mul(&self, a: &[U256], b: &[U256]) -> Vec<U256> {
+        let size = self.modulus.len();
+        let mut t = vec![U256::ZERO; size + 2];
+        for b_i in b {
+            let mut carry = U256::ZERO;
+            for (t_j, a_j) in t.iter_mut().zip(a) {
+                let word = &(&*t_j + &(a_j * b_i)) + &carry;
+                (*t_j, carry) = self.split(&word);
+            }
+            let word = &t[size] + &carry;
+            (t[size], t[size + 1]) = self.split(&word);
+
+            let m = self.low(&(&t[0] * &self.inverse));
+            let (_, mut carry) = self.split(&(&t[0] + &(&m * &self.modulus[0])));
+            for j in 1..size {
+                let word = &(&t[j] + &(&m * &self.modulus[j])) + &carry;
+                (t[j - 1], carry) = self.split(&word);
+            }
+            let word = &t[size] + &carry;
+            (t[size - 1], carry) = self.split(&word);
+            t[size] = &t[size + 1] + &carry;
+        }
+        self.reduce(t[..=size].to_vec())
+    }
+
+    /// `a + b mod modulus`
+    fn add(&self, a: &[U256], b: &[U256]) -> Vec<U256> {
+        let mut sum = Vec::with_capacity(a.len() + 1);
+        let mut carry = U256::ZERO;
+        for (a_j, b_j) in a.iter().zip(b) {
+            let (digit, next) = self.split(&(&(a_j + b_j) + &carry));
+            sum.push(digit);
+            carry = next;
+        }
+        sum.push(carry);
+        self.reduce(sum)
+    }
+
+    /// Subtract the modulus once from a value below twice the modulus, given with one extra digit
+    fn reduce(&self, mut value: Vec<U256>) -> Vec<U256> {
+        let size = self.modulus.len();
+        let below_modulus = value[size] == U256::ZERO
+            && value[..size]
+                .iter()
+                .rev()
+                .zip(self.modulus.iter().rev())
+                .find(|(digit, modulus_digit)| digit != modulus_digit)
+                .is_some_and(|(digit, modulus_digit)| digit < modulus_digit);
+        if !below_modulus {
+            let radix = &self.mask + &U256::from_u8(1);
+            let mut borrow = U256::ZERO;
+            for (digit, modulus_digit) in value.iter_mut().zip(&self.modulus) {
+                let word = &(&(&*digit + &radix) - modulus_digit) - &borrow;
+                let (low, high) = self.split(&word);
+                *digit = low;
+                borrow = &U256::from_u8(1) - &high;
+            }
+        }
+        value.truncate(size);
+        value
+    }
+
+    /// Low digit of a word
+    fn low(&self, word: &U256) -> U256 {
+        word & &self.mask
+    }
+
+    /// Low and high digits of a word
+    fn split(&self, word: &U256) -> (U256, U256) {
+        (word & &self.mask, word >> &self.shift)
+    }
+}
+
+/// Little-endian bytes of 2^128 - 1
+fn mask_le_bytes() -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    bytes[..DIGIT_BYTES].fill(0xff);
+    bytes
+}
+
+/// `count` little-endian digits of a big-endian value of at most `count` digits
+fn digits(be_bytes: &[u8], count: usize) -> Vec<U256> {
+    let mut le_bytes: Vec<u8> = be_bytes.iter().rev().copied().collect();
+    le_bytes.resize(count * DIGIT_BYTES, 0);
+    le_bytes
+        .chunks(DIGIT_BYTES)
+        .map(|chunk| {
+            let mut word = [0u8; 32];
+            word[..DIGIT_BYTES].copy_from_slice(chunk);
+            U256::from_le_bytes(word)
+        })
+        .collect()
+}
+
+/// Big-endian bytes of little-endian digits
+fn digits_to_be_bytes(digits: &[U256]) -> Vec<u8> {
+    digits
+        .iter()
+        .rev()
+        .flat_map(|digit| digit.as_le_bytes()[..DIGIT_BYTES].iter().rev().copied())
+        .collect()
+}
+
+/// `len` bytes of `input` from `offset`, zero padded past its end as the precompile reads its input
+pub(crate) fn padded(input: &[u8], offset: usize, len: usize) -> Vec<u8> {
+    let mut bytes = vec![0u8; len];
+    if offset < input.len() {
+        let available = (input.len() - offset).min(len);
+        bytes[..available].copy_from_slice(&input[offset..offset + available]);
+    }
+    bytes
+}
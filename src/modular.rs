@@ -0,0 +1,251 @@
+use hex_literal::hex;
+use openvm_algebra_guest::{DivUnsafe, IntMod, Sqrt};
+use openvm_ecc_guest::{
+    k256::{Secp256k1Coord, Secp256k1Scalar},
+    p256::{P256Coord, P256Scalar},
+};
+use openvm_pairing_guest::{
+    bls12_381::{Fp as Bls12_381Fp, Scalar as Bls12_381Scalar},
+    bn254::{Fp as Bn254Fp, Scalar as Bn254Scalar},
+};
+
+/// Known-answer test case for arithmetic modulo one of the moduli in `moduli_init!`.
+/// All values are big-endian and padded to the limb count of the modulus.
+/// Operands are sha256 digests of fixed labels reduced modulo p, expected values computed in Python.
+struct ModularTestCase {
+    modulus: &'static [u8],
+    a: &'static [u8],
+    b: &'static [u8],
+    sum: &'static [u8],
+    difference: &'static [u8],
+    product: &'static [u8],
+    quotient: &'static [u8],
+    negation: &'static [u8],
+    square: &'static [u8],
+    /// smallest quadratic non-residue
+    non_residue: &'static [u8],
+    /// the largest representable value `2^(8 * NUM_LIMBS) - 1` reduced modulo p
+    max_reduced: &'static [u8],
+}
+
+/// Test case for the bn254 coordinate field.
+const BN254_FP_TEST_CASE: ModularTestCase = ModularTestCase {
+    modulus: &hex!("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47"),
+    a: &hex!("296da4681e577b3382606dac05c4ee00d362046c320c44f2a176b8aac974f660"),
+    b: &hex!("16af4e7c1b1666a81ef56694467acf4746e3a67c9f0a5b44b2e235e6530db03c"),
+    sum: &hex!("0fb8a471583c41b1e9058e89cabe64ea82c4405768a4d5aa1838627a4405a955"),
+    difference: &hex!("12be55ec0341148b636b0717bf4a1eb98c7e5def9301e9adee9482c476674624"),
+    product: &hex!("18c6aadb2b878937ef90204d6db12ac09882bc5db048f46acbee69372a0d66cc"),
+    quotient: &hex!("09ae3391e50f26000c512bf81d9d31a42ec329a166d252de0259bdccae244b40"),
+    negation: &hex!("06f6aa0ac2da24f635efd80a7bbc6a5cc41f66253665859a9aa9d36c0f0806e7"),
+    square: &hex!("27ac37f402f997c9e907edc1bc7c2a6af8ccebd1bb469f1f39cd8cbd6844092e"),
+    non_residue: &hex!("0000000000000000000000000000000000000000000000000000000000000003"),
+    max_reduced: &hex!("0e0a77c19a07df2f666ea36f7879462c0a78eb28f5c70b3dd35d438dc58f0d9c"),
+};
+
+/// Test case for the bn254 scalar field.
+const BN254_SCALAR_TEST_CASE: ModularTestCase = ModularTestCase {
+    modulus: &hex!("30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001"),
+    a: &hex!("040f7271f7ed33108c662361708e302b7f27e4ded13cb74e2b307d7a8de51497"),
+    b: &hex!("2c6b7e75ff245d4b54b1672c467970cc77bb219914ce69005b1d9eea0e2a98c8"),
+    sum: &hex!("0016a27515dff03228c744d73586489aceaf1e2f6c51afbd426c26d0ac0fad5e"),
+    difference: &hex!("0808426ed9fa75eef00501ebab9617bc2fa0ab8e3627bedf13f4d4246fba7bd0"),
+    product: &hex!("0beb454b5562a4e1032ba28bd2bb455c30af654ca9e21371cfdb8fb83257372d"),
+    quotient: &hex!("16b8d5022a16660716bd7a6a0ad0d2e3a61dab844a3f11080e239bb9b2f6d370"),
+    negation: &hex!("2c54dc00e9446d192bea225510f32831a90c0369a87cb94318b17819621aeb6a"),
+    square: &hex!("2059bc1d1ab31c0916732a893309b8c090c9c3743f6d6cd44c13007083a073a4"),
+    non_residue: &hex!("0000000000000000000000000000000000000000000000000000000000000005"),
+    max_reduced: &hex!("0e0a77c19a07df2f666ea36f7879462e36fc76959f60cd29ac96341c4ffffffa"),
+};
+
+/// Test case for the secp256k1 coordinate field.
+const SECP256K1_COORD_TEST_CASE: ModularTestCase = ModularTestCase {
+    modulus: &hex!("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f"),
+    a: &hex!("a450ac35fb87887f156f1317589c88205d93df66d3a6c4a4530022c63fff6363"),
+    b: &hex!("e915feb9263e47778e6d5e5bf639d95a0ca4e8a28d3366049582ff07757ed72e"),
+    sum: &hex!("8d66aaef21c5cff6a3dc71734ed6617a6a38c80960da2aa8e88321ceb57e3e62"),
+    difference: &hex!("bb3aad7cd54941078701b4bb6262aec650eef6c446735e9fbd7d23bdca808864"),
+    product: &hex!("64fce70c62dbdf0563638f1e092d06ad96b16cd94146f2a24bc5ee765958cf24"),
+    quotient: &hex!("ad44ab14c152f55190983cb492927c510456d6872bd893f666e863780e5a0bd5"),
+    negation: &hex!("5baf53ca04787780ea90ece8a76377dfa26c20992c593b5bacffdd38c00098cc"),
+    square: &hex!("3240dd2a43d49b83a5f1a5f5ad48ae40589224967a84600090d643176416f021"),
+    non_residue: &hex!("0000000000000000000000000000000000000000000000000000000000000003"),
+    max_reduced: &hex!("00000000000000000000000000000000000000000000000000000001000003d0"),
+};
+
+/// Test case for the secp256k1 scalar field.
+const SECP256K1_SCALAR_TEST_CASE: ModularTestCase = ModularTestCase {
+    modulus: &hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"),
+    a: &hex!("48883015097e0cfce6a022f2e0bae401b8f11f49dc76b81e2608b234449da62a"),
+    b: &hex!("b32e6db66970e31369059e09663d010324e61d274522585cffe6902be4ce7430"),
+    sum: &hex!("fbb69dcb72eef0104fa5c0fc46f7e504ddd73c712199107b25ef4260296c1a5a"),
+    difference: &hex!("9559c25ea00d29e97d9a84e97a7de2fd4eb9df09469cfffce5f480953005733b"),
+    product: &hex!("8178756795cb465366047c22f39ac1799669391d44774e6e72410cafcb3ef266"),
+    quotient: &hex!("2374123bd857d5e99c24286d520c329ff4db4d3098850af3216c5df3c95737f9"),
+    negation: &hex!("b777cfeaf681f303195fdd0d1f451bfd01bdbd9cd2d1e81d99c9ac588b989b17"),
+    square: &hex!("4c5f173cff3e110ac837951e5d76edab04b968322c51101c22c54e349dd31dd2"),
+    non_residue: &hex!("0000000000000000000000000000000000000000000000000000000000000005"),
+    max_reduced: &hex!("000000000000000000000000000000014551231950b75fc4402da1732fc9bebe"),
+};
+
+/// Test case for the secp256r1 (p256) coordinate field.
+const P256_COORD_TEST_CASE: ModularTestCase = ModularTestCase {
+    modulus: &hex!("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"),
+    a: &hex!("578c326e6ffc906a0d3ea9d1b752e6a6883a8fb37b116a1113b0431119e07005"),
+    b: &hex!("f2c120d64d306628c950f41eda691ceb95b1b41a87f04497ff296eb16b6e3caa"),
+    sum: &hex!("4a4d5345bd2cf691d68f9df091bc03921dec43cd0301aea912d9b1c2854eacb0"),
+    difference: &hex!("64cb119722cc2a4243edb5b2dce9c9baf288db99f32125791486d45fae72335a"),
+    product: &hex!("7105e07432bc2b7b886d849a9659ae38ce6debc891c01d20dd5d4eb2fb47bf2c"),
+    quotient: &hex!("bf2b319b507411468edff08b5130b16a77ffbd1b125b5333399959f2b9a27811"),
+    negation: &hex!("a873cd9090036f96f2c1562e48ad195977c5704d84ee95eeec4fbceee61f8ffa"),
+    square: &hex!("0feb9e26e909288d2610bb255d22b696047f09fa0ce43ac9838f7e6c86d4cd43"),
+    non_residue: &hex!("0000000000000000000000000000000000000000000000000000000000000003"),
+    max_reduced: &hex!("00000000fffffffeffffffffffffffffffffffff000000000000000000000000"),
+};
+
+/// Test case for the secp256r1 (p256) scalar field.
+const P256_SCALAR_TEST_CASE: ModularTestCase = ModularTestCase {
+    modulus: &hex!("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"),
+    a: &hex!("704c242df038247a4897e2720f43f61849f7ce3828b168f7f7f107a891e740d3"),
+    b: &hex!("0be54eec1ebf7bfa9ae64eb0b23bc3c2d17b6a6fd88aa526edcb971f2f3bb603"),
+    sum: &hex!("7c31731a0ef7a074e37e3122c17fb9db1b7338a8013c0e1ee5bc9ec7c122f6d6"),
+    difference: &hex!("6466d541d178a87fadb193c15d083255787c63c85026c3d10a25708962ab8ad0"),
+    product: &hex!("e64b0adf02ea2ef21086674494f4491a5261c400da97a4c89596d25ac9fff9ad"),
+    quotient: &hex!("e1d31584c85b7493202f03ed76d0d2037b4184101d2c475abbabe2d16febccff"),
+    negation: &hex!("8fb3dbd10fc7db86b7681d8df0bc09e772ef2c757e66358cfbc8c31a6a7be47e"),
+    square: &hex!("1e0800579093c6b92ae40c88c11a12242c4d197207ad1a505dc5e260fa70f056"),
+    non_residue: &hex!("0000000000000000000000000000000000000000000000000000000000000007"),
+    max_reduced: &hex!("00000000ffffffff00000000000000004319055258e8617b0c46353d039cdaae"),
+};
+
+/// Test case for the bls12_381 coordinate field.
+const BLS12_381_FP_TEST_CASE: ModularTestCase = ModularTestCase {
+    modulus: &hex!("1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab"),
+    a: &hex!("0d59d94013050cf8c38d0b8e47a8ff3ba953737f58546e4ec32a10a5eb81a06203aa097fecd191b05efa515143783543"),
+    b: &hex!("0d5999c0dce058bbee28bbb88ff12f7fa77b0d8e50176e72a002e2ac7da66c24fdc659b5b5c76a04543bf2bcc740af74"),
+    sum: &hex!("00b26116b6657f1a669a1f90944e81e3ec573588b4e6ca01fbfc20b172771662e2c46336f144fbb4f937440e0ab93a0c"),
+    difference: &hex!("00003f7f3624b43cd5644fd5b7b7cfbc01d865f1083cffdc23272df96ddb343d05e3afca370a27ac0abe5e947c3785cf"),
+    product: &hex!("0475e0f9be7cff6ca67d5ecafac61cb060d4705cccf6480af17174b8c87118663e84f793e831b6fd221e8293edc2112d"),
+    quotient: &hex!("0ab16bf8b7e14005a29cc2a2a42e238cb198a900824f3b59592e6bd2d3f6cd2c7b5f2ce7f017d6f812a0e4634966e88d"),
+    negation: &hex!("0ca738aa267ad9a1878e9c27fba2ad9bbb23d8059b30a470a406c1fb0b2f55c21b01f67ec4826e4f5b04aeaebc877568"),
+    square: &hex!("0bd0e3e3c6216c451e15cc17d1d106f697816cbb30ee0fded2f0056cb977968382d9567adb679cc7dfafad74a307dd99"),
+    non_residue: &hex!("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002"),
+    max_reduced: &hex!("15f65ec3fa80e4935c071a97a256ec6d77ce5853705257455f48985753c758baebf4000bc40c0002760900000002fffc"),
+};
+
+/// Test case for the bls12_381 scalar field.
+const BLS12_381_SCALAR_TEST_CASE: ModularTestCase = ModularTestCase {
+    modulus: &hex!("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001"),
+    a: &hex!("4e2fcf01e5a4b043eabbdef8cfba2b6d6c23e3ff178a32df6d192e111dcecb6b"),
+    b: &hex!("40cb755f0e7cdd197887659089d1598f756b9c4320dc7950ccaccb370ae61ca7"),
+    sum: &hex!("1b0d9d0dca84101530096c814fe9acf78dd1dc3f3868503139c5f94928b4e811"),
+    difference: &hex!("0d6459a2d727d32a7234796845e8d1ddf6b847bbf6adb98ea06c62da12e8aec4"),
+    product: &hex!("1c0575ac86bb6376dabe877484c0f23e65720681d5ab3f7adf4ac1a5fe0422d6"),
+    quotient: &hex!("45342924527dcb34551d8a304a80352a41717f5d1bd107cb36b56f9271045ab3"),
+    negation: &hex!("25bdd85143f8cd04487df90f39e7ac97e799c003e874291f92e6d1ede2313496"),
+    square: &hex!("50b6dfd96c6b40031a9cdb550e144f04e51d3daac2590ee4fac03f56025ef140"),
+    non_residue: &hex!("0000000000000000000000000000000000000000000000000000000000000005"),
+    max_reduced: &hex!("1824b159acc5056f998c4fefecbc4ff55884b7fa0003480200000001fffffffd"),
+};
+
+/// Run the modular arithmetic test case of every modulus declared in `moduli_init!`
+pub fn run_modular_tests() {
+    run_modular_test_case::<Bn254Fp>(&BN254_FP_TEST_CASE);
+    run_modular_test_case::<Bn254Scalar>(&BN254_SCALAR_TEST_CASE);
+    run_modular_test_case::<Secp256k1Coord>(&SECP256K1_COORD_TEST_CASE);
+    run_modular_test_case::<Secp256k1Scalar>(&SECP256K1_SCALAR_TEST_CASE);
+    run_modular_test_case::<P256Coord>(&P256_COORD_TEST_CASE);
+    run_modular_test_case::<P256Scalar>(&P256_SCALAR_TEST_CASE);
+    run_modular_test_case::<Bls12_381Fp>(&BLS12_381_FP_TEST_CASE);
+    run_modular_test_case::<Bls12_381Scalar>(&BLS12_381_SCALAR_TEST_CASE);
+}
+
+/// Check the known-answer values, boundary values and non-canonical inputs for one modulus.
+/// Non-canonical values are never compared directly, since equality requires reduced operands,
+/// only the results of arithmetic on them.
+fn run_modular_test_case<F: IntMod + Sqrt>(case: &ModularTestCase) {
+    let zero = <F as IntMod>::ZERO;
+    let one = <F as IntMod>::ONE;
+
+    // the modulus the chip was configured with
+    let modulus_be: Vec<u8> = F::MODULUS.as_ref().iter().rev().copied().collect();
+    assert_eq!(modulus_be, case.modulus);
+
+    // known-answer arithmetic
+    let a = F::from_be_bytes(case.a);
+    let b = F::from_be_bytes(case.b);
+    assert_eq!(a.clone() + b.clone(), F::from_be_bytes(case.sum));
+    assert_eq!(a.clone() - b.clone(), F::from_be_bytes(case.difference));
+    assert_eq!(a.clone() * b.clone(), F::from_be_bytes(case.product));
+    assert_eq!(
+        a.clone().div_unsafe(b.clone()),
+        F::from_be_bytes(case.quotient)
+    );
+    assert_eq!(-a.clone(), F::from_be_bytes(case.negation));
+    assert_eq!(a.clone() * a.clone(), F::from_be_bytes(case.square));
+    assert_eq!(a.clone().to_be_bytes().as_ref(), case.a);
+
+    // is_eq
+    assert!(a == a.clone());
+    assert!(a != b);
+    assert!(a != a.clone() + one.clone());
+
+    // sqrt
+    let square = F::from_be_bytes(case.square);
+    let root = square.sqrt().expect("square must have a square root");
+    assert_eq!(root.clone() * root.clone(), square);
+    assert!(root == a || root == -a.clone());
+    assert!(F::from_be_bytes(case.non_residue).sqrt().is_none());
+    assert_eq!(zero.sqrt(), Some(zero.clone()));
+
+    // boundary values 0, 1 and p - 1
+    let p_minus_one = zero.clone() - one.clone();
+    assert_eq!(p_minus_one.clone() + one.clone(), zero);
+    assert_eq!(-one.clone(), p_minus_one);
+    assert_eq!(-zero.clone(), zero);
+    assert_eq!(p_minus_one.clone() * p_minus_one.clone(), one);
+    assert_eq!(p_minus_one.clone().div_unsafe(p_minus_one.clone()), one);
+    assert_eq!(one.clone().div_unsafe(p_minus_one.clone()), p_minus_one);
+    assert_eq!(a.clone() * zero.clone(), zero);
+    assert_eq!(a.clone() * one.clone(), a);
+    assert_eq!(a.clone() * p_minus_one.clone(), -a.clone());
+    assert!(p_minus_one.is_reduced());
+
+    // non-canonical p is congruent to 0
+    let p = F::from_be_bytes(case.modulus);
+    assert!(!p.is_reduced());
+    assert_eq!(p.clone() + zero.clone(), zero);
+    assert_eq!(p.clone() + one.clone(), one);
+    assert_eq!(p.clone() - one.clone(), p_minus_one);
+    assert_eq!(p.clone() * a.clone(), zero);
+    assert_eq!(a.clone() + p.clone(), a);
+    assert_eq!(a.clone() - p.clone(), a);
+    assert_eq!(-p.clone(), zero);
+
+    // non-canonical p + 1 is congruent to 1
+    let mut p_plus_one_bytes = case.modulus.to_vec();
+    for byte in p_plus_one_bytes.iter_mut().rev() {
+        let (sum, carry) = byte.overflowing_add(1);
+        *byte = sum;
+        if !carry {
+            break;
+        }
+    }
+    let p_plus_one = F::from_be_bytes(&p_plus_one_bytes);
+    assert!(!p_plus_one.is_reduced());
+    assert_eq!(p_plus_one.clone() * a.clone(), a);
+    assert_eq!(p_plus_one.clone() - one.clone(), zero);
+
+    // non-canonical 2^(8 * NUM_LIMBS) - 1
+    let max = F::from_be_bytes(&vec![0xff; case.modulus.len()]);
+    assert!(!max.is_reduced());
+    assert_eq!(
+        max.clone() + zero.clone(),
+        F::from_be_bytes(case.max_reduced)
+    );
+    assert_eq!(
+        max.clone() * one.clone(),
+        F::from_be_bytes(case.max_reduced)
+    );
+    assert_eq!(max - F::from_be_bytes(case.max_reduced), zero);
+}
@@ -0,0 +1,832 @@
+use hex_literal::hex;
+use openvm_algebra_guest::{field::FieldExtension, DivUnsafe, Field, IntMod};
+use openvm_ecc_guest::AffinePoint;
+use openvm_pairing_guest::{
+    bls12_381::{Bls12_381, Fp as Bls12_381Fp, Fp12 as Bls12_381Fp12, Fp2 as Bls12_381Fp2},
+    bn254::{Bn254, Fp as Bn254Fp, Fp12 as Bn254Fp12, Fp2 as Bn254Fp2},
+    pairing::{MultiMillerLoop, PairingCheck},
+};
+
+/// G1 point given as big-endian affine `(x, y)` coordinates, with `(0, 0)` the point at infinity.
+type G1Bytes = (&'static [u8], &'static [u8]);
+/// G2 point given as big-endian affine `(x.c0, x.c1, y.c0, y.c1)` coordinates, with all zeros the point at infinity.
+type G2Bytes = (&'static [u8], &'static [u8], &'static [u8], &'static [u8]);
+
+/// Test case for a pairing check `e(g1[0], g2[0]) * ... * e(g1[n - 1], g2[n - 1]) == 1`.
+struct PairingTestCase {
+    g1: &'static [G1Bytes],
+    g2: &'static [G2Bytes],
+    expected: bool,
+}
+
+/// Vector of test cases for the Bn254 pairing check.
+/// Points are multiples of the generators by sha256 digests of fixed labels, computed in Python.
+const BN254_PAIRING_TEST_CASES: &[PairingTestCase] = &[
+    // e(aP, bQ) = e(abP, Q)
+    PairingTestCase {
+        g1: &[
+            (
+                &hex!("136270f7a5c9e9d9a4a597596c09f4c2e3252463ec66e1e1f0b9fadfd777ef10"),
+                &hex!("15171bd2295ba508e771d229d56556e96fa8ff6a1a52092aafe87ac42b6b02e7"),
+            ),
+            (
+                &hex!("246f306c5fddb9d514e53958c68c9bc23242578cc5bcd47e1f53177fbd7841ff"),
+                &hex!("19bd265f3867b597d911772a5941a4135fc549056bcbc7d6ba9e1e74f8ad1e89"),
+            ),
+        ],
+        g2: &[
+            (
+                &hex!("1870beeb8406736a7e4b48f3f57ce102bddee0de50a6221f2b671b5020aba2a0"),
+                &hex!("0b7da8adb0c76ba714517984ce298c720e8881e43e715b4049628007394b6898"),
+                &hex!("181cd7c85693dc9d0ca83f937f2590ea0953869b8d805833a2847f620c3755c7"),
+                &hex!("06f0b6690ca19f0bf5f6028b6933901e6bd9494416cdad40a0d7ff69ee78155d"),
+            ),
+            (
+                &hex!("1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"),
+                &hex!("198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"),
+                &hex!("12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"),
+                &hex!("090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"),
+            ),
+        ],
+        expected: true,
+    },
+    // e(aP, bQ) = e(P, abQ)
+    PairingTestCase {
+        g1: &[
+            (
+                &hex!("136270f7a5c9e9d9a4a597596c09f4c2e3252463ec66e1e1f0b9fadfd777ef10"),
+                &hex!("15171bd2295ba508e771d229d56556e96fa8ff6a1a52092aafe87ac42b6b02e7"),
+            ),
+            (
+                &hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+                &hex!("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45"),
+            ),
+        ],
+        g2: &[
+            (
+                &hex!("1870beeb8406736a7e4b48f3f57ce102bddee0de50a6221f2b671b5020aba2a0"),
+                &hex!("0b7da8adb0c76ba714517984ce298c720e8881e43e715b4049628007394b6898"),
+                &hex!("181cd7c85693dc9d0ca83f937f2590ea0953869b8d805833a2847f620c3755c7"),
+                &hex!("06f0b6690ca19f0bf5f6028b6933901e6bd9494416cdad40a0d7ff69ee78155d"),
+            ),
+            (
+                &hex!("1e3a0750272456fffb86f20108230c1f9590fdbdab0b36f91bc834d5d520c5f9"),
+                &hex!("28cdc6b248e89c5c597984e48dee0e3bf3e12f1f653826e15f98a5f2fc6f7302"),
+                &hex!("076a0ff7d0967870a74bf7596f55a8fcd6c5cf14f8c7467dbe4080bb342a89a2"),
+                &hex!("11155e1cb5e7d21dc553d77c2f63f357c956d28aacb5070f27556db415f0422a"),
+            ),
+        ],
+        expected: true,
+    },
+    // e(aP, bQ) != e((ab + 1)P, Q)
+    PairingTestCase {
+        g1: &[
+            (
+                &hex!("136270f7a5c9e9d9a4a597596c09f4c2e3252463ec66e1e1f0b9fadfd777ef10"),
+                &hex!("15171bd2295ba508e771d229d56556e96fa8ff6a1a52092aafe87ac42b6b02e7"),
+            ),
+            (
+                &hex!("1f0b970e539f709d6a553f74926571ae3275fce4deaa89c79966b74d76aeaf2d"),
+                &hex!("04d48862ff5463febbb08dda2280b267bf337b0082b8956b3cd0dadf9c874059"),
+            ),
+        ],
+        g2: &[
+            (
+                &hex!("1870beeb8406736a7e4b48f3f57ce102bddee0de50a6221f2b671b5020aba2a0"),
+                &hex!("0b7da8adb0c76ba714517984ce298c720e8881e43e715b4049628007394b6898"),
+                &hex!("181cd7c85693dc9d0ca83f937f2590ea0953869b8d805833a2847f620c3755c7"),
+                &hex!("06f0b6690ca19f0bf5f6028b6933901e6bd9494416cdad40a0d7ff69ee78155d"),
+            ),
+            (
+                &hex!("1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"),
+                &hex!("198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"),
+                &hex!("12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"),
+                &hex!("090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"),
+            ),
+        ],
+        expected: false,
+    },
+    // e(aP, bQ) e(cP, dQ) = e((ab + cd)P, Q)
+    PairingTestCase {
+        g1: &[
+            (
+                &hex!("136270f7a5c9e9d9a4a597596c09f4c2e3252463ec66e1e1f0b9fadfd777ef10"),
+                &hex!("15171bd2295ba508e771d229d56556e96fa8ff6a1a52092aafe87ac42b6b02e7"),
+            ),
+            (
+                &hex!("23bd3cf83339a957131ef6c8bcfe6c696e05652cf387ade8c0bf123db3efd897"),
+                &hex!("0f7808fa2275fa252bde6a927bb10627b7755299af0e828a61a4b03a68f01231"),
+            ),
+            (
+                &hex!("10fb47ebf583a5579b2ad392e6363a9d0e4e8fd6a936079f3174ea158cf4c5a7"),
+                &hex!("01e4f2fa8aa732713866ea97d5b1e1f84b8f57fbb97d1742c91d1b9b23c98e76"),
+            ),
+        ],
+        g2: &[
+            (
+                &hex!("1870beeb8406736a7e4b48f3f57ce102bddee0de50a6221f2b671b5020aba2a0"),
+                &hex!("0b7da8adb0c76ba714517984ce298c720e8881e43e715b4049628007394b6898"),
+                &hex!("181cd7c85693dc9d0ca83f937f2590ea0953869b8d805833a2847f620c3755c7"),
+                &hex!("06f0b6690ca19f0bf5f6028b6933901e6bd9494416cdad40a0d7ff69ee78155d"),
+            ),
+            (
+                &hex!("02c81e0937e5aa14811549f8f9f33b5e1bcf9d051da5234cc714488ff835b6f3"),
+                &hex!("1a4a383d9fad2026ee4486602acc952d4f39237df6170513cc87b1ff88a8fbde"),
+                &hex!("17269dd7ea16a8e3284466c94744530d56d8503895083036e1554240990be207"),
+                &hex!("231145a375665cc810725da4ffa3e9d639eb9ec3e98b29784735d4a386c0eba7"),
+            ),
+            (
+                &hex!("1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"),
+                &hex!("198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"),
+                &hex!("12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"),
+                &hex!("090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"),
+            ),
+        ],
+        expected: true,
+    },
+    // e(aP, bQ) e(cP, Q) != e((ab + cd)P, Q)
+    PairingTestCase {
+        g1: &[
+            (
+                &hex!("136270f7a5c9e9d9a4a597596c09f4c2e3252463ec66e1e1f0b9fadfd777ef10"),
+                &hex!("15171bd2295ba508e771d229d56556e96fa8ff6a1a52092aafe87ac42b6b02e7"),
+            ),
+            (
+                &hex!("23bd3cf83339a957131ef6c8bcfe6c696e05652cf387ade8c0bf123db3efd897"),
+                &hex!("0f7808fa2275fa252bde6a927bb10627b7755299af0e828a61a4b03a68f01231"),
+            ),
+            (
+                &hex!("10fb47ebf583a5579b2ad392e6363a9d0e4e8fd6a936079f3174ea158cf4c5a7"),
+                &hex!("01e4f2fa8aa732713866ea97d5b1e1f84b8f57fbb97d1742c91d1b9b23c98e76"),
+            ),
+        ],
+        g2: &[
+            (
+                &hex!("1870beeb8406736a7e4b48f3f57ce102bddee0de50a6221f2b671b5020aba2a0"),
+                &hex!("0b7da8adb0c76ba714517984ce298c720e8881e43e715b4049628007394b6898"),
+                &hex!("181cd7c85693dc9d0ca83f937f2590ea0953869b8d805833a2847f620c3755c7"),
+                &hex!("06f0b6690ca19f0bf5f6028b6933901e6bd9494416cdad40a0d7ff69ee78155d"),
+            ),
+            (
+                &hex!("1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"),
+                &hex!("198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"),
+                &hex!("12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"),
+                &hex!("090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"),
+            ),
+            (
+                &hex!("1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"),
+                &hex!("198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"),
+                &hex!("12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"),
+                &hex!("090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"),
+            ),
+        ],
+        expected: false,
+    },
+    // e(P, Q) e(-P, Q) = 1
+    PairingTestCase {
+        g1: &[
+            (
+                &hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+                &hex!("0000000000000000000000000000000000000000000000000000000000000002"),
+            ),
+            (
+                &hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+                &hex!("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45"),
+            ),
+        ],
+        g2: &[
+            (
+                &hex!("1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"),
+                &hex!("198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"),
+                &hex!("12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"),
+                &hex!("090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"),
+            ),
+            (
+                &hex!("1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"),
+                &hex!("198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"),
+                &hex!("12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"),
+                &hex!("090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"),
+            ),
+        ],
+        expected: true,
+    },
+    // e(P, Q) e(P, -Q) = 1
+    PairingTestCase {
+        g1: &[
+            (
+                &hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+                &hex!("0000000000000000000000000000000000000000000000000000000000000002"),
+            ),
+            (
+                &hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+                &hex!("0000000000000000000000000000000000000000000000000000000000000002"),
+            ),
+        ],
+        g2: &[
+            (
+                &hex!("1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"),
+                &hex!("198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"),
+                &hex!("12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"),
+                &hex!("090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"),
+            ),
+            (
+                &hex!("1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"),
+                &hex!("198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"),
+                &hex!("1d9befcd05a5323e6da4d435f3b617cdb3af83285c2df711ef39c01571827f9d"),
+                &hex!("275dc4a288d1afb3cbb1ac09187524c7db36395df7be3b99e673b13a075a65ec"),
+            ),
+        ],
+        expected: true,
+    },
+    // e(P, Q) != 1
+    PairingTestCase {
+        g1: &[(
+            &hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+            &hex!("0000000000000000000000000000000000000000000000000000000000000002"),
+        )],
+        g2: &[(
+            &hex!("1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"),
+            &hex!("198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"),
+            &hex!("12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"),
+            &hex!("090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"),
+        )],
+        expected: false,
+    },
+    // infinity in G1 is skipped
+    PairingTestCase {
+        g1: &[
+            // point at infinity
+            (
+                &hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+                &hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+            ),
+            (
+                &hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+                &hex!("0000000000000000000000000000000000000000000000000000000000000002"),
+            ),
+            (
+                &hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+                &hex!("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45"),
+            ),
+        ],
+        g2: &[
+            (
+                &hex!("1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"),
+                &hex!("198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"),
+                &hex!("12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"),
+                &hex!("090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"),
+            ),
+            (
+                &hex!("1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"),
+                &hex!("198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"),
+                &hex!("12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"),
+                &hex!("090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"),
+            ),
+            (
+                &hex!("1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"),
+                &hex!("198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"),
+                &hex!("12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"),
+                &hex!("090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"),
+            ),
+        ],
+        expected: true,
+    },
+    // infinity in G2 is skipped
+    PairingTestCase {
+        g1: &[
+            (
+                &hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+                &hex!("0000000000000000000000000000000000000000000000000000000000000002"),
+            ),
+            (
+                &hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+                &hex!("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45"),
+            ),
+            (
+                &hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+                &hex!("0000000000000000000000000000000000000000000000000000000000000002"),
+            ),
+        ],
+        g2: &[
+            (
+                &hex!("1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"),
+                &hex!("198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"),
+                &hex!("12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"),
+                &hex!("090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"),
+            ),
+            (
+                &hex!("1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"),
+                &hex!("198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"),
+                &hex!("12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"),
+                &hex!("090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"),
+            ),
+            // point at infinity
+            (
+                &hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+                &hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+                &hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+                &hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+            ),
+        ],
+        expected: true,
+    },
+    // infinity does not cancel a nontrivial pair
+    PairingTestCase {
+        g1: &[
+            // point at infinity
+            (
+                &hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+                &hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+            ),
+            (
+                &hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+                &hex!("0000000000000000000000000000000000000000000000000000000000000002"),
+            ),
+        ],
+        g2: &[
+            (
+                &hex!("1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"),
+                &hex!("198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"),
+                &hex!("12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"),
+                &hex!("090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"),
+            ),
+            (
+                &hex!("1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"),
+                &hex!("198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"),
+                &hex!("12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"),
+                &hex!("090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"),
+            ),
+        ],
+        expected: false,
+    },
+];
+
+/// Vector of test cases for the Bls12_381 pairing check.
+/// Points are multiples of the generators by sha256 digests of fixed labels, computed in Python.
+const BLS12_381_PAIRING_TEST_CASES: &[PairingTestCase] = &[
+    // e(aP, bQ) = e(abP, Q)
+    PairingTestCase {
+        g1: &[
+            (
+                &hex!("1723944fc966baf0986503db73746699ca974630335f2f0f99aa69e197b7d8a8f17dc63eb23a2182e1d47e3c05f2cab7"),
+                &hex!("0094885c648f418fed2cbf63b13e32a8c48939a84a5e5ae9af598e1bcfb79952cce990ff2ac5d91d94b7cad5e212df9c"),
+            ),
+            (
+                &hex!("13acc19d37c9e61a4323753b2c5f426a6323a863bcc0b0a0adbb9251c189a8eadaa08db0a122cbc2e195bb1afab48879"),
+                &hex!("11d420e3a61d1fb941dbbdb2cff00fd59292fb9b7226b373a42cc9485ee2a48b09906abb76b9bf246fcee2b5b1ab6431"),
+            ),
+        ],
+        g2: &[
+            (
+                &hex!("1855375f51a82822ef7fe8297e1955d22b2b4bd156fc6542a872ca9a3f7c3bc07c175b12e5318f8ab2f7b98eda1e92f2"),
+                &hex!("0650d8b2e4f8461d327b1d470009474cefe5a14cb99a26a33a60a8bc209e56eb8556e35d61c5f24e7df67791be6368ee"),
+                &hex!("0f0543aff3c4bdb0e398c9500c61f923dea2aa1ec8cd47f0a51e5cc7d146255dd95fc821968b6183ee416b445d32deff"),
+                &hex!("155cb03534831fd413ce31348dbd79f7c72a397464ed9dc4321721f85a7a4c253d4a0ab38c27ce3b47cde8a44d6526e2"),
+            ),
+            (
+                &hex!("024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"),
+                &hex!("13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"),
+                &hex!("0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801"),
+                &hex!("0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be"),
+            ),
+        ],
+        expected: true,
+    },
+    // e(aP, bQ) = e(P, abQ)
+    PairingTestCase {
+        g1: &[
+            (
+                &hex!("1723944fc966baf0986503db73746699ca974630335f2f0f99aa69e197b7d8a8f17dc63eb23a2182e1d47e3c05f2cab7"),
+                &hex!("0094885c648f418fed2cbf63b13e32a8c48939a84a5e5ae9af598e1bcfb79952cce990ff2ac5d91d94b7cad5e212df9c"),
+            ),
+            (
+                &hex!("17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"),
+                &hex!("114d1d6855d545a8aa7d76c8cf2e21f267816aef1db507c96655b9d5caac42364e6f38ba0ecb751bad54dcd6b939c2ca"),
+            ),
+        ],
+        g2: &[
+            (
+                &hex!("1855375f51a82822ef7fe8297e1955d22b2b4bd156fc6542a872ca9a3f7c3bc07c175b12e5318f8ab2f7b98eda1e92f2"),
+                &hex!("0650d8b2e4f8461d327b1d470009474cefe5a14cb99a26a33a60a8bc209e56eb8556e35d61c5f24e7df67791be6368ee"),
+                &hex!("0f0543aff3c4bdb0e398c9500c61f923dea2aa1ec8cd47f0a51e5cc7d146255dd95fc821968b6183ee416b445d32deff"),
+                &hex!("155cb03534831fd413ce31348dbd79f7c72a397464ed9dc4321721f85a7a4c253d4a0ab38c27ce3b47cde8a44d6526e2"),
+            ),
+            (
+                &hex!("042926e235b86f1ee2dc69d57741a4d2eca50804e72ab8bb7ca2421f06fd497268d9feb051bdfb7de41a5e4fd622fe44"),
+                &hex!("04e0711ac8ea6e42e114148260cd7ecc82f56cc0c199deb968bc65aac434671e18656d055fba00cc94905a73720aa79f"),
+                &hex!("0d92835773f2902afa4ef0ca38a6b70c1689f72e8d376a6258dd4ca0e21a9510205ba7589f090f9baccf6f2087659955"),
+                &hex!("077ed1024f74c3ee90dbebab82991437ad60535b2b2a48d45bbb8fe49bc6fc8be697efd92e0a50338e58bd5c7d0081af"),
+            ),
+        ],
+        expected: true,
+    },
+    // e(aP, bQ) != e((ab + 1)P, Q)
+    PairingTestCase {
+        g1: &[
+            (
+                &hex!("1723944fc966baf0986503db73746699ca974630335f2f0f99aa69e197b7d8a8f17dc63eb23a2182e1d47e3c05f2cab7"),
+                &hex!("0094885c648f418fed2cbf63b13e32a8c48939a84a5e5ae9af598e1bcfb79952cce990ff2ac5d91d94b7cad5e212df9c"),
+            ),
+            (
+                &hex!("0341fb0692e0f2d080730ae5d81ae392c3699f550e3725f0432d45fd4743b989d59a3abb4445a9e88ea59556a777e83d"),
+                &hex!("1090bb7cf5c0eb4b8466eba99411ff58b47ec911b5571b1fae6dc070c7c32ee5f71d9b7d65f7c52acfac3f23401c6d84"),
+            ),
+        ],
+        g2: &[
+            (
+                &hex!("1855375f51a82822ef7fe8297e1955d22b2b4bd156fc6542a872ca9a3f7c3bc07c175b12e5318f8ab2f7b98eda1e92f2"),
+                &hex!("0650d8b2e4f8461d327b1d470009474cefe5a14cb99a26a33a60a8bc209e56eb8556e35d61c5f24e7df67791be6368ee"),
+                &hex!("0f0543aff3c4bdb0e398c9500c61f923dea2aa1ec8cd47f0a51e5cc7d146255dd95fc821968b6183ee416b445d32deff"),
+                &hex!("155cb03534831fd413ce31348dbd79f7c72a397464ed9dc4321721f85a7a4c253d4a0ab38c27ce3b47cde8a44d6526e2"),
+            ),
+            (
+                &hex!("024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"),
+                &hex!("13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"),
+                &hex!("0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801"),
+                &hex!("0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be"),
+            ),
+        ],
+        expected: false,
+    },
+    // e(aP, bQ) e(cP, dQ) = e((ab + cd)P, Q)
+    PairingTestCase {
+        g1: &[
+            (
+                &hex!("1723944fc966baf0986503db73746699ca974630335f2f0f99aa69e197b7d8a8f17dc63eb23a2182e1d47e3c05f2cab7"),
+                &hex!("0094885c648f418fed2cbf63b13e32a8c48939a84a5e5ae9af598e1bcfb79952cce990ff2ac5d91d94b7cad5e212df9c"),
+            ),
+            (
+                &hex!("02693e3c389ad840d71587405f9154fccea6c5f591c9869c12f9228dcb819054aa8d3753e791ec762841820dd9988dcd"),
+                &hex!("04724f3de6e6ff3c06787313da01b194b3887c18b98ef09c508cab19586855cc3dfd1cc1a6b4d6650ee074fc2b3c8523"),
+            ),
+            (
+                &hex!("1035743c84d0a62259986c0d5269c0aa7af8398ab4957c43763e16a3ed394111a56392c9c84762a959f8128c3f94aef9"),
+                &hex!("0464d7246f662a72dcbb5aefdb7a889a66f4bda30d8a6a1e05a25bdcaf747204835af77671ef10a8dc4cec0281e0a730"),
+            ),
+        ],
+        g2: &[
+            (
+                &hex!("1855375f51a82822ef7fe8297e1955d22b2b4bd156fc6542a872ca9a3f7c3bc07c175b12e5318f8ab2f7b98eda1e92f2"),
+                &hex!("0650d8b2e4f8461d327b1d470009474cefe5a14cb99a26a33a60a8bc209e56eb8556e35d61c5f24e7df67791be6368ee"),
+                &hex!("0f0543aff3c4bdb0e398c9500c61f923dea2aa1ec8cd47f0a51e5cc7d146255dd95fc821968b6183ee416b445d32deff"),
+                &hex!("155cb03534831fd413ce31348dbd79f7c72a397464ed9dc4321721f85a7a4c253d4a0ab38c27ce3b47cde8a44d6526e2"),
+            ),
+            (
+                &hex!("16d9fa52205d21489eec18ca91704a6e74a063bd7930192a041fa8cd13d3f07e992a2d242e9f2926ee1916ac560aef3d"),
+                &hex!("064f20ae937288e61f8153d1868acac21614ad4d454b8ea11f46c7e4b890dd09360d095122bbc67141274efc11bb859a"),
+                &hex!("06ca444a72e2e1a96a5d1c09b697285456bd33224adf60f79c7a7f39766ec97899942426e22c39159ccafdb6279567fc"),
+                &hex!("09b9eecb89c511525b64dc71bba5a90e5ab5201a9015e9d3878a27bc31fe39a6c954ffc5f1c1095221c95dbade09de21"),
+            ),
+            (
+                &hex!("024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"),
+                &hex!("13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"),
+                &hex!("0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801"),
+                &hex!("0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be"),
+            ),
+        ],
+        expected: true,
+    },
+    // e(aP, bQ) e(cP, Q) != e((ab + cd)P, Q)
+    PairingTestCase {
+        g1: &[
+            (
+                &hex!("1723944fc966baf0986503db73746699ca974630335f2f0f99aa69e197b7d8a8f17dc63eb23a2182e1d47e3c05f2cab7"),
+                &hex!("0094885c648f418fed2cbf63b13e32a8c48939a84a5e5ae9af598e1bcfb79952cce990ff2ac5d91d94b7cad5e212df9c"),
+            ),
+            (
+                &hex!("02693e3c389ad840d71587405f9154fccea6c5f591c9869c12f9228dcb819054aa8d3753e791ec762841820dd9988dcd"),
+                &hex!("04724f3de6e6ff3c06787313da01b194b3887c18b98ef09c508cab19586855cc3dfd1cc1a6b4d6650ee074fc2b3c8523"),
+            ),
+            (
+                &hex!("1035743c84d0a62259986c0d5269c0aa7af8398ab4957c43763e16a3ed394111a56392c9c84762a959f8128c3f94aef9"),
+                &hex!("0464d7246f662a72dcbb5aefdb7a889a66f4bda30d8a6a1e05a25bdcaf747204835af77671ef10a8dc4cec0281e0a730"),
+            ),
+        ],
+        g2: &[
+            (
+                &hex!("1855375f51a82822ef7fe8297e1955d22b2b4bd156fc6542a872ca9a3f7c3bc07c175b12e5318f8ab2f7b98eda1e92f2"),
+                &hex!("0650d8b2e4f8461d327b1d470009474cefe5a14cb99a26a33a60a8bc209e56eb8556e35d61c5f24e7df67791be6368ee"),
+                &hex!("0f0543aff3c4bdb0e398c9500c61f923dea2aa1ec8cd47f0a51e5cc7d146255dd95fc821968b6183ee416b445d32deff"),
+                &hex!("155cb03534831fd413ce31348dbd79f7c72a397464ed9dc4321721f85a7a4c253d4a0ab38c27ce3b47cde8a44d6526e2"),
+            ),
+            (
+                &hex!("024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"),
+                &hex!("13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"),
+                &hex!("0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801"),
+                &hex!("0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be"),
+            ),
+            (
+                &hex!("024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"),
+                &hex!("13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"),
+                &hex!("0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801"),
+                &hex!("0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be"),
+            ),
+        ],
+        expected: false,
+    },
+    // e(P, Q) e(-P, Q) = 1
+    PairingTestCase {
+        g1: &[
+            (
+                &hex!("17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"),
+                &hex!("08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1"),
+            ),
+            (
+                &hex!("17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"),
+                &hex!("114d1d6855d545a8aa7d76c8cf2e21f267816aef1db507c96655b9d5caac42364e6f38ba0ecb751bad54dcd6b939c2ca"),
+            ),
+        ],
+        g2: &[
+            (
+                &hex!("024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"),
+                &hex!("13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"),
+                &hex!("0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801"),
+                &hex!("0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be"),
+            ),
+            (
+                &hex!("024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"),
+                &hex!("13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"),
+                &hex!("0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801"),
+                &hex!("0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be"),
+            ),
+        ],
+        expected: true,
+    },
+    // e(P, Q) e(P, -Q) = 1
+    PairingTestCase {
+        g1: &[
+            (
+                &hex!("17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"),
+                &hex!("08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1"),
+            ),
+            (
+                &hex!("17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"),
+                &hex!("08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1"),
+            ),
+        ],
+        g2: &[
+            (
+                &hex!("024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"),
+                &hex!("13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"),
+                &hex!("0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801"),
+                &hex!("0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be"),
+            ),
+            (
+                &hex!("024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"),
+                &hex!("13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"),
+                &hex!("0d1b3cc2c7027888be51d9ef691d77bcb679afda66c73f17f9ee3837a55024f78c71363275a75d75d86bab79f74782aa"),
+                &hex!("13fa4d4a0ad8b1ce186ed5061789213d993923066dddaf1040bc3ff59f825c78df74f2d75467e25e0f55f8a00fa030ed"),
+            ),
+        ],
+        expected: true,
+    },
+    // e(P, Q) != 1
+    PairingTestCase {
+        g1: &[
+            (
+                &hex!("17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"),
+                &hex!("08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1"),
+            ),
+        ],
+        g2: &[
+            (
+                &hex!("024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"),
+                &hex!("13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"),
+                &hex!("0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801"),
+                &hex!("0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be"),
+            ),
+        ],
+        expected: false,
+    },
+    // infinity in G1 is skipped
+    PairingTestCase {
+        g1: &[
+            // point at infinity
+            (
+                &hex!("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+                &hex!("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+            ),
+            (
+                &hex!("17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"),
+                &hex!("08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1"),
+            ),
+            (
+                &hex!("17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"),
+                &hex!("114d1d6855d545a8aa7d76c8cf2e21f267816aef1db507c96655b9d5caac42364e6f38ba0ecb751bad54dcd6b939c2ca"),
+            ),
+        ],
+        g2: &[
+            (
+                &hex!("024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"),
+                &hex!("13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"),
+                &hex!("0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801"),
+                &hex!("0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be"),
+            ),
+            (
+                &hex!("024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"),
+                &hex!("13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"),
+                &hex!("0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801"),
+                &hex!("0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be"),
+            ),
+            (
+                &hex!("024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"),
+                &hex!("13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"),
+                &hex!("0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801"),
+                &hex!("0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be"),
+            ),
+        ],
+        expected: true,
+    },
+    // infinity in G2 is skipped
+    PairingTestCase {
+        g1: &[
+            (
+                &hex!("17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"),
+                &hex!("08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1"),
+            ),
+            (
+                &hex!("17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"),
+                &hex!("114d1d6855d545a8aa7d76c8cf2e21f267816aef1db507c96655b9d5caac42364e6f38ba0ecb751bad54dcd6b939c2ca"),
+            ),
+            (
+                &hex!("17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"),
+                &hex!("08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1"),
+            ),
+        ],
+        g2: &[
+            (
+                &hex!("024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"),
+                &hex!("13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"),
+                &hex!("0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801"),
+                &hex!("0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be"),
+            ),
+            (
+                &hex!("024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"),
+                &hex!("13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"),
+                &hex!("0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801"),
+                &hex!("0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be"),
+            ),
+            // point at infinity
+            (
+                &hex!("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+                &hex!("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+                &hex!("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+                &hex!("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+            ),
+        ],
+        expected: true,
+    },
+    // infinity does not cancel a nontrivial pair
+    PairingTestCase {
+        g1: &[
+            // point at infinity
+            (
+                &hex!("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+                &hex!("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+            ),
+            (
+                &hex!("17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"),
+                &hex!("08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1"),
+            ),
+        ],
+        g2: &[
+            (
+                &hex!("024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"),
+                &hex!("13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"),
+                &hex!("0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801"),
+                &hex!("0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be"),
+            ),
+            (
+                &hex!("024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"),
+                &hex!("13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"),
+                &hex!("0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801"),
+                &hex!("0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be"),
+            ),
+        ],
+        expected: false,
+    },
+];
+
+/// bn254 curve seed x
+const BN254_SEED: u128 = 0x44e992b44a6909f1;
+/// Absolute value of the bls12_381 curve seed x = -0xd201000000010000
+const BLS12_381_SEED_ABS: u128 = 0xd201000000010000;
+
+/// Run all pairing check test cases for both Bn254 and Bls12_381.
+/// Besides the outcome of `PairingCheck::pairing_check`, each case checks the final exponentiation
+/// hint `(c, u)` of `PairingCheck::pairing_check_hint` against the Miller loop output f of its
+/// pairs without a point at infinity:
+/// `f * u == c^λ` must hold exactly when the product is one, and fail for a tampered `c`.
+/// λ is `6x + 2 + q - q^2 + q^3` for bn254 and `q - x` for bls12_381.
+pub fn run_pairing_tests() {
+    for case in BN254_PAIRING_TEST_CASES {
+        let decode = |finite_only| {
+            decode_points::<Bn254>(case, finite_only, Bn254Fp::from_be_bytes, |c0, c1| {
+                Bn254Fp2::new(Bn254Fp::from_be_bytes(c0), Bn254Fp::from_be_bytes(c1))
+            })
+        };
+        let (p, q) = decode(false);
+        assert_eq!(Bn254::pairing_check(&p, &q).is_ok(), case.expected);
+
+        let (p, q) = decode(true);
+        check_final_exp_hint(
+            Bn254::multi_miller_loop(&p, &q),
+            Bn254::pairing_check_hint(&p, &q),
+            bn254_c_lambda,
+            case.expected,
+        );
+    }
+    for case in BLS12_381_PAIRING_TEST_CASES {
+        let decode = |finite_only| {
+            decode_points::<Bls12_381>(case, finite_only, Bls12_381Fp::from_be_bytes, |c0, c1| {
+                Bls12_381Fp2::new(
+                    Bls12_381Fp::from_be_bytes(c0),
+                    Bls12_381Fp::from_be_bytes(c1),
+                )
+            })
+        };
+        let (p, q) = decode(false);
+        assert_eq!(Bls12_381::pairing_check(&p, &q).is_ok(), case.expected);
+
+        let (p, q) = decode(true);
+        check_final_exp_hint(
+            Bls12_381::multi_miller_loop(&p, &q),
+            Bls12_381::pairing_check_hint(&p, &q),
+            bls12_381_c_lambda,
+            case.expected,
+        );
+    }
+}
+
+/// Decode the points of a test case, leaving out pairs with a point at infinity if `finite_only`.
+/// Such pairs contribute one to the product.
+fn decode_points<C: PairingCheck>(
+    case: &PairingTestCase,
+    finite_only: bool,
+    to_fp: impl Fn(&[u8]) -> C::Fp,
+    to_fp2: impl Fn(&[u8], &[u8]) -> C::Fp2,
+) -> (Vec<AffinePoint<C::Fp>>, Vec<AffinePoint<C::Fp2>>) {
+    let is_zero = |coordinates: &[&[u8]]| {
+        coordinates
+            .iter()
+            .all(|coordinate| coordinate.iter().all(|&byte| byte == 0))
+    };
+    case.g1
+        .iter()
+        .zip(case.g2)
+        .filter(|&(&(x, y), &(x0, x1, y0, y1))| {
+            !finite_only || !(is_zero(&[x, y]) || is_zero(&[x0, x1, y0, y1]))
+        })
+        .map(|(&(x, y), &(x0, x1, y0, y1))| {
+            (
+                AffinePoint::new(to_fp(x), to_fp(y)),
+                AffinePoint::new(to_fp2(x0, x1), to_fp2(y0, y1)),
+            )
+        })
+        .unzip()
+}
+
+/// Check the final exponentiation hint `(c, u)` of a Miller loop output `f`: `f * u == c^λ` holds
+/// exactly for a product equal to one, and never for the tampered hint `c + 1`
+fn check_final_exp_hint<F: Field>(
+    f: F,
+    (c, u): (F, F),
+    c_lambda: impl Fn(&F) -> F,
+    expected: bool,
+) {
+    let product = f * u;
+    assert_eq!(
+        product == c_lambda(&c),
+        expected,
+        "final exponentiation hint"
+    );
+    assert!(
+        product != c_lambda(&(c + F::ONE)),
+        "tampered final exponentiation hint"
+    );
+}
+
+/// `c^λ` with λ = 6x + 2 + q - q^2 + q^3 for bn254
+fn bn254_c_lambda(c: &Bn254Fp12) -> Bn254Fp12 {
+    let c_q = c.frobenius_map(1);
+    let c_q2 = c.frobenius_map(2);
+    let c_q3 = c.frobenius_map(3);
+    (pow(c, 6 * BN254_SEED + 2) * c_q * c_q3).div_unsafe(c_q2)
+}
+
+/// `c^λ` with λ = q - x for bls12_381, whose seed x is negative
+fn bls12_381_c_lambda(c: &Bls12_381Fp12) -> Bls12_381Fp12 {
+    c.frobenius_map(1) * pow(c, BLS12_381_SEED_ABS)
+}
+
+/// `base^exponent` with left-to-right square-and-multiply
+fn pow<F: Field>(base: &F, exponent: u128) -> F {
+    let mut result = F::ONE;
+    for bit in (0..u128::BITS - exponent.leading_zeros()).rev() {
+        result.square_assign();
+        if (exponent >> bit) & 1 == 1 {
+            result = result * base.clone();
+        }
+    }
+    result
+}
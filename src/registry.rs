@@ -0,0 +1,343 @@
+use hex_literal::hex;
+use revm_precompile::{u64_to_address, PrecompileErrors, PrecompileSpecId, Precompiles};
+use revm_primitives::{Address, Bytes, Env};
+
+use crate::{bn128, ecrecover, kzg_point_evaluation, modexp, sha256};
+
+/// Specs the registry vectors run under, in activation order
+const REGISTRY_SPECS: [PrecompileSpecId; 7] = [
+    PrecompileSpecId::HOMESTEAD,
+    PrecompileSpecId::BYZANTIUM,
+    PrecompileSpecId::ISTANBUL,
+    PrecompileSpecId::BERLIN,
+    PrecompileSpecId::CANCUN,
+    PrecompileSpecId::PRAGUE,
+    PrecompileSpecId::LATEST,
+];
+
+/// Precompile addresses
+const ECRECOVER: u64 = 0x01;
+const SHA256: u64 = 0x02;
+const RIPEMD160: u64 = 0x03;
+const IDENTITY: u64 = 0x04;
+const MODEXP: u64 = 0x05;
+const ECADD: u64 = 0x06;
+const ECMUL: u64 = 0x07;
+const ECPAIRING: u64 = 0x08;
+const BLAKE2F: u64 = 0x09;
+const POINT_EVALUATION: u64 = 0x0a;
+
+/// Availability of a precompile, checked with one probe call.
+/// The gas schedule lists the gas charged for the probe from each spec on; its first entry is the spec
+/// that activates the precompile, so an implementation bound under the wrong spec shows in the gas.
+struct AvailabilityProbe {
+    address: u64,
+    gas_schedule: &'static [(PrecompileSpecId, u64)],
+    input: &'static [u8],
+    output: &'static [u8],
+}
+
+/// Probes of the mainnet precompiles of this revm fork, expected outputs computed in Python
+const AVAILABILITY_PROBES: &[AvailabilityProbe] = &[
+    // ecrecover of an empty input recovers nothing
+    AvailabilityProbe {
+        address: ECRECOVER,
+        gas_schedule: &[(PrecompileSpecId::HOMESTEAD, 3_000)],
+        input: &[],
+        output: &[],
+    },
+    AvailabilityProbe {
+        address: SHA256,
+        gas_schedule: &[(PrecompileSpecId::HOMESTEAD, 60)],
+        input: &[],
+        output: &hex!("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"),
+    },
+    AvailabilityProbe {
+        address: RIPEMD160,
+        gas_schedule: &[(PrecompileSpecId::HOMESTEAD, 600)],
+        input: &[],
+        output: &hex!("0000000000000000000000009c1185a5c5e9fc54612808977ee8f548b2258d31"),
+    },
+    AvailabilityProbe {
+        address: IDENTITY,
+        gas_schedule: &[(PrecompileSpecId::HOMESTEAD, 15)],
+        input: &[],
+        output: &[],
+    },
+    // 2^3 mod 5 with one-byte lengths, EIP-2565 raised the minimum gas to 200 in Berlin
+    AvailabilityProbe {
+        address: MODEXP,
+        gas_schedule: &[
+            (PrecompileSpecId::BYZANTIUM, 0),
+            (PrecompileSpecId::BERLIN, 200),
+        ],
+        input: &hex!("000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000001020305"),
+        output: &hex!("03"),
+    },
+    // point at infinity plus itself
+    AvailabilityProbe {
+        address: ECADD,
+        gas_schedule: &[
+            (PrecompileSpecId::BYZANTIUM, 500),
+            (PrecompileSpecId::ISTANBUL, 150),
+        ],
+        input: &[],
+        output: &[0; 64],
+    },
+    // point at infinity times zero
+    AvailabilityProbe {
+        address: ECMUL,
+        gas_schedule: &[
+            (PrecompileSpecId::BYZANTIUM, 40_000),
+            (PrecompileSpecId::ISTANBUL, 6_000),
+        ],
+        input: &[],
+        output: &[0; 64],
+    },
+    // the empty product of pairings is one
+    AvailabilityProbe {
+        address: ECPAIRING,
+        gas_schedule: &[
+            (PrecompileSpecId::BYZANTIUM, 100_000),
+            (PrecompileSpecId::ISTANBUL, 45_000),
+        ],
+        input: &[],
+        output: &hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+    },
+    // EIP-152 test vector 5: blake2b-512 of "abc", 12 rounds at one gas each
+    AvailabilityProbe {
+        address: BLAKE2F,
+        gas_schedule: &[(PrecompileSpecId::ISTANBUL, 12)],
+        input: &hex!("0000000c48c9bdf267e6096a3ba7ca8485ae67bb2bf894fe72f36e3cf1361d5f3af54fa5d182e6ad7f520e511f6c3e2b8c68059b6bbd41fbabd9831f79217e1319cde05b61626300000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000300000000000000000000000000000001"),
+        output: &hex!("ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d17d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"),
+    },
+    // go-ethereum pointEvaluation.json vector
+    AvailabilityProbe {
+        address: POINT_EVALUATION,
+        gas_schedule: &[(PrecompileSpecId::CANCUN, 50_000)],
+        input: &hex!("01e798154708fe7789429634053cbf9f99b619f9f084048927333fce637f549b564c0a11a0f704f4fc3e8acfe0f8245f0ad1347b378fbf96e206da11a5d3630624d25032e67a7e6a4910df5834b8fe70e6bcfeeac0352434196bdf4b2485d5a18f59a8d2a1a625a17f3fea0fe5eb8c896db3764f3185481bc22f91b4aaffcca25f26936857bc3a7c2539ea8ec3a952b7873033e038326e87ed3e1276fd140253fa08e9fc25fb2d9a98527fc22a2c9612fbeafdad446cbc7bcdbdcd780af2c16a"),
+        output: &hex!("000000000000000000000000000000000000000000000000000000000000100073eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001"),
+    },
+];
+
+/// Addresses no spec may bind in the guest: the EIP-2537 BLS12-381 precompiles 0x0b to 0x13,
+/// built only with revm's `blst` feature, and the RIP-7212 P256VERIFY at 0x100, which the
+/// `secp256r1` feature compiles but leaves to rollup specs to install
+const UNAVAILABLE_ADDRESSES: &[u64] =
+    &[0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0x10, 0x11, 0x12, 0x13, 0x100];
+
+/// Run the module test vectors through the precompile set of every spec, resolving each precompile
+/// by address instead of calling its function directly, and check the gas charged under that spec.
+/// Vectors of precompiles activated after a spec must find no precompile at their address.
+/// P256VERIFY is not part of any spec, so its vectors are not run here.
+pub fn run_registry_tests() {
+    for spec in REGISTRY_SPECS {
+        let precompiles = Precompiles::new(spec);
+
+        for (input, expected) in ecrecover::ECRECOVER_TEST_CASES {
+            // the recovered address is returned as a left-padded word, a failed recovery as no output
+            let word = expected.map(|address| address.into_word());
+            let output = word.as_ref().map_or(&[][..], |word| word.as_slice());
+            check_registry_case(precompiles, spec, ECRECOVER, input, Ok(output), 3_000);
+        }
+
+        for (input, expected) in sha256::SHA256_TEST_CASES {
+            let gas = 60 + 12 * words(input.len());
+            check_registry_case(precompiles, spec, SHA256, input, Ok(expected), gas);
+            // identity echoes its input
+            let gas = 15 + 3 * words(input.len());
+            check_registry_case(precompiles, spec, IDENTITY, input, Ok(input), gas);
+        }
+
+        if spec < PrecompileSpecId::BYZANTIUM {
+            for address in [MODEXP, ECADD, ECMUL, ECPAIRING] {
+                assert_unavailable(precompiles, spec, address);
+            }
+        } else {
+            for (input, expected) in modexp::MODEXP_TEST_CASES
+                .iter()
+                .chain(modexp::MODEXP_EDGE_CASES)
+            {
+                let gas = modexp_gas(spec, input);
+                check_registry_case(precompiles, spec, MODEXP, input, Ok(expected), gas);
+            }
+
+            // EIP-1108 repriced the bn128 precompiles in Istanbul
+            let istanbul = spec >= PrecompileSpecId::ISTANBUL;
+            for (input, expected) in bn128::ECADD_CASES {
+                let gas = if istanbul { 150 } else { 500 };
+                let expected = expected.as_ref().copied();
+                check_registry_case(precompiles, spec, ECADD, input, expected, gas);
+            }
+            for (input, expected) in bn128::ECMUL_CASES {
+                let gas = if istanbul { 6_000 } else { 40_000 };
+                let expected = expected.as_ref().copied();
+                check_registry_case(precompiles, spec, ECMUL, input, expected, gas);
+            }
+            for (input, expected) in bn128::ECPAIRING_CASES {
+                let pairs = (input.len() / 192) as u64;
+                let gas = if istanbul {
+                    45_000 + 34_000 * pairs
+                } else {
+                    100_000 + 80_000 * pairs
+                };
+                let expected = expected.as_ref().copied();
+                check_registry_case(precompiles, spec, ECPAIRING, input, expected, gas);
+            }
+        }
+
+        if spec < PrecompileSpecId::CANCUN {
+            assert_unavailable(precompiles, spec, POINT_EVALUATION);
+        } else {
+            for (input, expected) in kzg_point_evaluation::KZG_POINT_EVALUATION_TEST_CASES
+                .iter()
+                .chain(kzg_point_evaluation::KZG_SYNTHETIC_PROOF_TEST_CASES)
+                .chain(kzg_point_evaluation::KZG_INPUT_VALIDATION_TEST_CASES)
+            {
+                let expected = expected.as_ref().map(|bytes| bytes.as_slice());
+                let gas = kzg_point_evaluation::KZG_POINT_EVALUATION_GAS_COST;
+                check_registry_case(precompiles, spec, POINT_EVALUATION, input, expected, gas);
+            }
+        }
+    }
+}
+
+/// Check that the precompile set of every spec holds exactly the precompiles activated up to it,
+/// each bound to the implementation and gas schedule of that spec, and nothing at the addresses
+/// of precompiles the guest must not include.
+/// This revm fork has no Osaka spec id; `LATEST` is its newest set, the same as Prague's.
+pub fn run_availability_tests() {
+    for spec in REGISTRY_SPECS {
+        let precompiles = Precompiles::new(spec);
+
+        let mut expected_addresses = Vec::new();
+        for probe in AVAILABILITY_PROBES {
+            let gas = probe
+                .gas_schedule
+                .iter()
+                .take_while(|(activation, _)| *activation <= spec)
+                .last();
+            match gas {
+                Some(&(_, gas)) => {
+                    expected_addresses.push(u64_to_address(probe.address));
+                    check_registry_case(
+                        precompiles,
+                        spec,
+                        probe.address,
+                        probe.input,
+                        Ok(probe.output),
+                        gas,
+                    );
+                }
+                None => assert_unavailable(precompiles, spec, probe.address),
+            }
+        }
+
+        for &address in UNAVAILABLE_ADDRESSES {
+            assert_unavailable(precompiles, spec, address);
+        }
+
+        let mut addresses: Vec<Address> = precompiles.addresses().copied().collect();
+        addresses.sort();
+        assert_eq!(
+            addresses, expected_addresses,
+            "{spec:?} precompile addresses"
+        );
+    }
+
+    let mut latest: Vec<Address> = Precompiles::new(PrecompileSpecId::LATEST)
+        .addresses()
+        .copied()
+        .collect();
+    let mut prague: Vec<Address> = Precompiles::new(PrecompileSpecId::PRAGUE)
+        .addresses()
+        .copied()
+        .collect();
+    latest.sort();
+    prague.sort();
+    assert_eq!(latest, prague, "LATEST and PRAGUE precompile addresses");
+}
+
+/// Call the precompile registered at `address` and compare its output and gas, or its error
+fn check_registry_case(
+    precompiles: &Precompiles,
+    spec: PrecompileSpecId,
+    address: u64,
+    input: &[u8],
+    expected: Result<&[u8], &PrecompileErrors>,
+    expected_gas: u64,
+) {
+    let precompile = precompiles
+        .get(&u64_to_address(address))
+        .unwrap_or_else(|| panic!("{spec:?}: no precompile at {address:#x}"));
+    let result = precompile.call_ref(&Bytes::copy_from_slice(input), u64::MAX, &Env::default());
+
+    match (result, expected) {
+        (Ok(output), Ok(expected_bytes)) => {
+            assert_eq!(
+                output.bytes.as_ref(),
+                expected_bytes,
+                "{spec:?} {address:#x}"
+            );
+            assert_eq!(output.gas_used, expected_gas, "{spec:?} {address:#x} gas");
+        }
+        (Err(error), Err(expected_error)) => {
+            assert_eq!(error, *expected_error, "{spec:?} {address:#x}");
+        }
+        (Ok(output), Err(expected_error)) => {
+            panic!("{spec:?} {address:#x}: expected error {expected_error:?}, but got success: {output:?}");
+        }
+        (Err(error), Ok(expected_bytes)) => {
+            panic!("{spec:?} {address:#x}: expected success with bytes {expected_bytes:?}, but got error: {error:?}");
+        }
+    }
+}
+
+/// Check that no precompile is registered at `address` under `spec`
+fn assert_unavailable(precompiles: &Precompiles, spec: PrecompileSpecId, address: u64) {
+    assert!(
+        precompiles.get(&u64_to_address(address)).is_none(),
+        "{spec:?}: unexpected precompile at {address:#x}"
+    );
+}
+
+/// Number of 32-byte words covering `len` bytes
+fn words(len: usize) -> u64 {
+    len.div_ceil(32) as u64
+}
+
+/// modexp gas of EIP-198 before Berlin and of EIP-2565 from Berlin on
+fn modexp_gas(spec: PrecompileSpecId, input: &[u8]) -> u64 {
+    let length =
+        |offset| u64::from_be_bytes(modexp::padded(input, offset + 24, 8).try_into().unwrap());
+    let (base_len, exp_len, mod_len) = (length(0), length(32), length(64));
+
+    // adjusted exponent length from the first 32 bytes of the exponent, zero padded
+    let exp_offset = 96usize.saturating_add(base_len as usize);
+    let exp_head = modexp::padded(input, exp_offset, exp_len.min(32) as usize);
+    let head_bits = exp_head.iter().position(|&byte| byte != 0).map_or(0, |i| {
+        (exp_head.len() - i) as u64 * 8 - exp_head[i].leading_zeros() as u64
+    });
+    let adjusted_exp_len = exp_len
+        .saturating_sub(32)
+        .saturating_mul(8)
+        .saturating_add(head_bits.saturating_sub(1));
+    let iterations = adjusted_exp_len.max(1) as u128;
+
+    // lengths beyond the guest's address space saturate, as revm saturates the gas to u64
+    let max_len = base_len.max(mod_len) as u128;
+    let gas = if spec < PrecompileSpecId::BERLIN {
+        let complexity = if max_len <= 64 {
+            max_len * max_len
+        } else if max_len <= 1024 {
+            max_len * max_len / 4 + 96 * max_len - 3_072
+        } else {
+            (max_len.saturating_mul(max_len) / 16).saturating_add(480 * max_len) - 199_680
+        };
+        complexity.saturating_mul(iterations) / 20
+    } else {
+        let words = max_len.div_ceil(8);
+        (words.saturating_mul(words).saturating_mul(iterations) / 3).max(200)
+    };
+    gas.min(u64::MAX as u128) as u64
+}
@@ -0,0 +1,354 @@
+use hex_literal::hex;
+use openvm_sha256_guest::sha256;
+use revm_precompile::modexp::run_inner;
+
+use crate::cycle_tracker;
+
+/// RSA public key with big-endian modulus `n` and public exponent `e`
+pub struct RsaPublicKey<'a> {
+    pub modulus: &'a [u8],
+    pub exponent: &'a [u8],
+}
+
+/// DER encoding of the sha256 `AlgorithmIdentifier` with NULL parameters and the digest's
+/// OCTET STRING header, which precede the digest in the PKCS#1 v1.5 `DigestInfo`
+const SHA256_DIGEST_INFO_PREFIX: [u8; 19] = hex!("3031300d060960864801650304020105000420");
+
+/// Verify an RSASSA-PKCS1-v1_5 signature with sha256 as in RFC 8017 section 8.2.2,
+/// computing `s^e mod n` with the modexp precompile.
+/// The signature must be exactly as long as the modulus and below it. The recovered encoded message
+/// is compared with `0x00 || 0x01 || 0xff.. || 0x00 || DigestInfo(sha256(message))` as a whole,
+/// so other hash algorithms, DigestInfo encodings and padding variants are rejected.
+pub fn verify_rsa_pkcs1v15_sha256(
+    public_key: &RsaPublicKey,
+    message: &[u8],
+    signature: &[u8],
+) -> bool {
+    let k = public_key.modulus.len();
+    let digest_info_len = SHA256_DIGEST_INFO_PREFIX.len() + 32;
+    // at least 8 padding bytes
+    if k < digest_info_len + 11 || signature.len() != k || signature >= public_key.modulus {
+        return false;
+    }
+
+    let input = [
+        &length_word(k)[..],
+        &length_word(public_key.exponent.len()),
+        &length_word(k),
+        signature,
+        public_key.exponent,
+        public_key.modulus,
+    ]
+    .concat();
+    let Ok(outcome) = run_inner(&input, u64::MAX, 0, |_, _, _, _| 0) else {
+        return false;
+    };
+
+    let mut encoded_message = vec![0xff; k];
+    encoded_message[0] = 0x00;
+    encoded_message[1] = 0x01;
+    encoded_message[k - digest_info_len - 1] = 0x00;
+    encoded_message[k - digest_info_len..k - 32].copy_from_slice(&SHA256_DIGEST_INFO_PREFIX);
+    encoded_message[k - 32..].copy_from_slice(&sha256(message));
+    outcome.bytes.as_ref() == encoded_message
+}
+
+/// Big-endian 32-byte length field of a modexp input
+fn length_word(len: usize) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&(len as u64).to_be_bytes());
+    word
+}
+
+/// Signatures checked against one public key.
+/// Each test case consists of (message, signature, expected_result).
+struct RsaTestGroup {
+    modulus: &'static [u8],
+    exponent: &'static [u8],
+    cases: &'static [(&'static [u8], &'static [u8], bool)],
+}
+
+/// Vector of RSA PKCS#1 v1.5 sha256 test groups per key size.
+/// Self-generated keys and signatures from Python (`cryptography`), not RFC 8017 or Wycheproof vectors.
+/// Malformed encodings are signed with the raw private key, so only the padding check can reject them.
+/// Wycheproof rsa_signature vectors run under the rsa-wycheproof feature.
+const RSA_TEST_GROUPS: &[RsaTestGroup] = &[
+    // 2048-bit modulus, e = 65537
+    RsaTestGroup {
+        modulus: &hex!("aa4e5fe809952fa3d78b4ac595f3ace1caca3020033b682c4099ac80475407cea7c806a8a81c2b87644416c01d90d5a464097639e1d4750f9b23d9aef3908e2cd24e3e21327b1faa1738d4979b14f6ee21fcf4b05e1dc93c1d6a76e5c09ae31594ce71114687d4c81c74391bd74f04e90d156c9cd613da89ff81afc96733bda53521d9b097c5af366f011ca4fb075962d6dcbb32f35eb12db07b94ee4ced8a9d8ec7dd08c293aad9ca5d6a71678f02c2c0162be33602d7f555a347adc03f028f6d34ba14447d44743f1a5b53f27a30c91ed320e789985e1b6497911081c62f22197b8cd367be989cd0f4a718308a73d4bad7308953065c758cf0cdb5f19a7027"),
+        exponent: &hex!("010001"),
+        cases: &[
+            // valid signature
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("91f55ce75b13187af79fc8789523571941a1e414d3eb5568fafbc2135b958a3d7888d6333f43b9865a6941ebdb28fc7a0a24108e7aeb47ba4e2fee51c2db8f63b4ef8327963077c8fb05b4b8e11e447e8239a69ecc80a5ae459bf73d010bebea6ff2479c288e11678d15ebc0e37ccc7d44383041116f1064a108e4160e9b5c5275ea40bc5dbaf1ef20f826ff397177edf336d673ab3aa42a808800931c2d478bf6b1b9a71f47dbc1b71a9f0d1503db30f08d305898572739bcb881dd229bd6a8eaaba2bb4fe31a33a109a644fe1b99a2c26a21ec55f62e29eb3231ac80114fcc152a65f61472e455d4d318a5342eab21fd56d06a3889ea2104b51d00a3e751b0"),
+                true,
+            ),
+            // valid signature of the empty message
+            (
+                b"",
+                &hex!("6fea6a575fec9ff1d70a833ae439a6b4ab7a10084e12a836ff1fad03b2113f9398c51cc703852d5a244a7944a03f75b6f6fb1231a7331e9df34e0be84f9bd35d4df4ea84326a12a5117f61b9b43126c5bcf64b4ad5b45650227d4b1aa2cf85c4e1c106dafa54d85851514080fd01eb272cbae6559a64d7ba8b9fde0459b45015eb4cca82caefecc78b67f8787d92c2e947e9e1d536f16699b196d4bd9ce3d5a3789ac33a6c8bd2c9bb959ee1c0905d456937a34e30cef7604917f7fe48b6b893ab6e02c74c53df52e626159e210938657913bec7f703f9434a2ca339fbcbb7512344e2324ed8a97b31520e6916d87c019a8ea8df19783923d9a1377745b62cbb"),
+                true,
+            ),
+            // message modified
+            (
+                b"openvm-tests rsa pkcs1v15 sha256!",
+                &hex!("91f55ce75b13187af79fc8789523571941a1e414d3eb5568fafbc2135b958a3d7888d6333f43b9865a6941ebdb28fc7a0a24108e7aeb47ba4e2fee51c2db8f63b4ef8327963077c8fb05b4b8e11e447e8239a69ecc80a5ae459bf73d010bebea6ff2479c288e11678d15ebc0e37ccc7d44383041116f1064a108e4160e9b5c5275ea40bc5dbaf1ef20f826ff397177edf336d673ab3aa42a808800931c2d478bf6b1b9a71f47dbc1b71a9f0d1503db30f08d305898572739bcb881dd229bd6a8eaaba2bb4fe31a33a109a644fe1b99a2c26a21ec55f62e29eb3231ac80114fcc152a65f61472e455d4d318a5342eab21fd56d06a3889ea2104b51d00a3e751b0"),
+                false,
+            ),
+            // last signature byte flipped
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("91f55ce75b13187af79fc8789523571941a1e414d3eb5568fafbc2135b958a3d7888d6333f43b9865a6941ebdb28fc7a0a24108e7aeb47ba4e2fee51c2db8f63b4ef8327963077c8fb05b4b8e11e447e8239a69ecc80a5ae459bf73d010bebea6ff2479c288e11678d15ebc0e37ccc7d44383041116f1064a108e4160e9b5c5275ea40bc5dbaf1ef20f826ff397177edf336d673ab3aa42a808800931c2d478bf6b1b9a71f47dbc1b71a9f0d1503db30f08d305898572739bcb881dd229bd6a8eaaba2bb4fe31a33a109a644fe1b99a2c26a21ec55f62e29eb3231ac80114fcc152a65f61472e455d4d318a5342eab21fd56d06a3889ea2104b51d00a3e751b1"),
+                false,
+            ),
+            // signature equal to the modulus
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("aa4e5fe809952fa3d78b4ac595f3ace1caca3020033b682c4099ac80475407cea7c806a8a81c2b87644416c01d90d5a464097639e1d4750f9b23d9aef3908e2cd24e3e21327b1faa1738d4979b14f6ee21fcf4b05e1dc93c1d6a76e5c09ae31594ce71114687d4c81c74391bd74f04e90d156c9cd613da89ff81afc96733bda53521d9b097c5af366f011ca4fb075962d6dcbb32f35eb12db07b94ee4ced8a9d8ec7dd08c293aad9ca5d6a71678f02c2c0162be33602d7f555a347adc03f028f6d34ba14447d44743f1a5b53f27a30c91ed320e789985e1b6497911081c62f22197b8cd367be989cd0f4a718308a73d4bad7308953065c758cf0cdb5f19a7027"),
+                false,
+            ),
+            // signature zero
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+                false,
+            ),
+            // signature one byte short
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("f55ce75b13187af79fc8789523571941a1e414d3eb5568fafbc2135b958a3d7888d6333f43b9865a6941ebdb28fc7a0a24108e7aeb47ba4e2fee51c2db8f63b4ef8327963077c8fb05b4b8e11e447e8239a69ecc80a5ae459bf73d010bebea6ff2479c288e11678d15ebc0e37ccc7d44383041116f1064a108e4160e9b5c5275ea40bc5dbaf1ef20f826ff397177edf336d673ab3aa42a808800931c2d478bf6b1b9a71f47dbc1b71a9f0d1503db30f08d305898572739bcb881dd229bd6a8eaaba2bb4fe31a33a109a644fe1b99a2c26a21ec55f62e29eb3231ac80114fcc152a65f61472e455d4d318a5342eab21fd56d06a3889ea2104b51d00a3e751b0"),
+                false,
+            ),
+            // signature with an extra leading zero byte
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("0091f55ce75b13187af79fc8789523571941a1e414d3eb5568fafbc2135b958a3d7888d6333f43b9865a6941ebdb28fc7a0a24108e7aeb47ba4e2fee51c2db8f63b4ef8327963077c8fb05b4b8e11e447e8239a69ecc80a5ae459bf73d010bebea6ff2479c288e11678d15ebc0e37ccc7d44383041116f1064a108e4160e9b5c5275ea40bc5dbaf1ef20f826ff397177edf336d673ab3aa42a808800931c2d478bf6b1b9a71f47dbc1b71a9f0d1503db30f08d305898572739bcb881dd229bd6a8eaaba2bb4fe31a33a109a644fe1b99a2c26a21ec55f62e29eb3231ac80114fcc152a65f61472e455d4d318a5342eab21fd56d06a3889ea2104b51d00a3e751b0"),
+                false,
+            ),
+            // PKCS#1 v1.5 signature over sha1
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("a382e5dcb101b98d849fcce592fe16df5dbc746ad1b0449e814af92895f49c150148dfe455c5fe37feb289f57776e305cc607d02fe4c5715bf637fcd091ac84486d356f7606768d498469a897160dbe962d72a9137b708772f84f6548c736fbdeb74292b159e9e1a2a8a1c6536fe948520cac29ab92c4ec4d9a7ed40c86a711fae1b48af9ddbcc55a1aa89f1558c4145263933ca3aec5fb86fcfc65335273ac4f93b324aeb7e5118c863d3acfcc3fb3a95ec7c0728bc22d99bcfdfaa07666590bf34d4f874a0647426d41c22fb6e9232b8b47407a20f30688a60e2b5cbd99e6e071ca4dd3685fe9834c2647f263e3fd36cc488c2cb5596458df40ad581348522"),
+                false,
+            ),
+            // DigestInfo without NULL parameters
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("8dfcbb6d72d225ea447df934b78f89b0e754e542fa8e90b15d28b796e7cdd18132b14a00361658e97aff9a7bcc7dd08bb91a8ec95d47583e4bb231acc504f6ebe13fba821c2c9e9d9bb227b26239b02cc4581c7f23440534fd64a30396a4d2c38d357de766f7004f63be3392f9de803fb1c316b380c0d6b8168d4acbdf3ed3ca56ad951d2b20e5d8fc8eb92e53be1b1c0d3c703e7af1296da48aafddc0a26e90f147413bf5d5a794818210580b5807c538edfc85634abc8bde19e796aa03b3492712280c07c4ed45ebb9e8e3f82c0f6fab2d43a1ab91c23977068b74fd0d5d73e5c79787c327c47b43404bd7243a39be003df466624d99963c9400a0a81f95a7"),
+                false,
+            ),
+            // block type 2
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("72052afed824f9c4ef7794df602aae95fad3ca1e5b182149500708ec694f050d955592909306ca2505bfda3bf524d326da1c46e7fbbd1a22fdfd58f1b62944f02e7c0d5abf243aa1e78e43306a557d882558a32eaa9c84a2af176d00f52057c0beb616143afa25a011f8197008b554b2fb3ca6e70f5cb03afcb61050a62e13aa1dc11c9619d76990f439d3214c8ccac8d3e6e487b63cd644c98150cff83c91f8740ec043e64362002d806d812da470976f741b073c33661a1b87bcd0927577d944baf3049d558c8fc7cc4ba70abe79b10815da1af467e1a10c77e8fc255b3f9382dd9c671f00db6a6cfbbff583fb459c33cf76d2bcca11e04f1da200f311a6e7"),
+                false,
+            ),
+            // padding byte 0xfe
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("773bd5b23017de1ccd82652645a5a8e7d37f318f5ac8a6354217fe1084986929bee0fa827e3065ff1d2b95e5d6049008f20d8187c7f68be16bc8cb6beec463da4d5345b148a42a8db984f414b7d757cdc66d7ea524bf4c23b5270d67c5b24fb0510504788ce69319a683cc12c32ac1dc14b4b3c32d749c0d7d8928fe2eb1cca638660dfb6a85354113debf93efe43a4567fda28fc06dfc5e9d9b39541918381721ff94793ba224617b7d64d3685d20f14e0acefc9e2ca72b9f497d4006a94a5c352932bab6d56050af77702d55dee17213eb73dc4639285867ec1d7425389139413fd29ba8c82c7054b4e6bb2f0999ecd9206dd5b38fae336d14e1a0cd5739d7"),
+                false,
+            ),
+            // short padding with garbage after the digest
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("0f82fbde7a10b174ef35f132de629bcac06565c6597084fa667f3e9af3bd2372f41e5105d741f33e910e6af3b4e8483f8ea55bb103d55f0e48ce5e032ac991d776213f1fb34534fe84771f60a989bc0615a90db481d4f7895ca2386d116a68e1b0638fa092e4c75fd3cb0836ac318c7e95c90f26095d03744d42ee61941ecf3436dd56eda0c8fddef13aa038a861243c9569b709697dea3ae9893e95ffd43014872c87f4fcaa1471dc8f7786237b9822ab22296d437d7227589d0ec3b5c3275c1d60a1cf7945f9c23f980db72547cbcbd87e67e15bdadecaae952b300e47559bf2d3f1ac5076dabfef8df0eb0349bf80679ba9b6069ef9d6c421593d0f63708f"),
+                false,
+            ),
+        ],
+    },
+    // 2048-bit modulus, e = 3
+    RsaTestGroup {
+        modulus: &hex!("e4d0cf73b7165b7e4c89eed1cdc271813838b221b7a3eb0fa5947741b7603559271cf819c5e2699dc4c71f6035059e69f1349137b8c473e3c26683b0fa13f2f31c9fea02a202467aa3e08708654173b4175fd65969de66d274f2c7402b7f1fcc71f7ccf7353d05b2807fa94e30aad7fac52ad77602725cb45145565adbfafa7ac69d20bb5654e2e6a83e360f6881c25a47b6ea5e81d796d1f461ecadd3e03c9343952052cfcd60a86455a4b9faa3ddef1f5b73d1a1807752dc077df69136cce1731bb443c0519caa6ed509b0ad7e92ea82932e69c98bd46ec441943a71551c314394ff621adb11edf7c4516f0f3ae34c326367f10b0408a6e6edfc08bf302753"),
+        exponent: &hex!("03"),
+        cases: &[
+            // valid signature
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("a106e224495fb793751f9ea6831d37bb0bb5fddf34d7e95e7ab1bd916a28e879d8a1b67530c5b9af8920aa3647ee09f358f72122b5a7b48102439a2bd3a9c592e64fce88437cf21933298aaa1e15dc17d600421c89cb939f100c01ac882ae9de81a6e4920423f9cd5333b1e340774cd9e28a07380cac42695591d8ac08301cf113364f1e9dad32769babbcd4f9a2e9bbdec2d7b59e9b30a533a871e8d1aae0657b5a0bf373c8c5627b4bd90d4e567a7b55c95e77feb30dd043d8d92a8eccfd73b7da2cbb4040379d9ceb0cd5c86183bbe4b6548be1eea5e4a53a201b01eed3e79475889f61f3f6d2e213fca014c17775db460f94a4a84aac8c62f82ad4205202"),
+                true,
+            ),
+            // message modified
+            (
+                b"openvm-tests rsa pkcs1v15 sha256!",
+                &hex!("a106e224495fb793751f9ea6831d37bb0bb5fddf34d7e95e7ab1bd916a28e879d8a1b67530c5b9af8920aa3647ee09f358f72122b5a7b48102439a2bd3a9c592e64fce88437cf21933298aaa1e15dc17d600421c89cb939f100c01ac882ae9de81a6e4920423f9cd5333b1e340774cd9e28a07380cac42695591d8ac08301cf113364f1e9dad32769babbcd4f9a2e9bbdec2d7b59e9b30a533a871e8d1aae0657b5a0bf373c8c5627b4bd90d4e567a7b55c95e77feb30dd043d8d92a8eccfd73b7da2cbb4040379d9ceb0cd5c86183bbe4b6548be1eea5e4a53a201b01eed3e79475889f61f3f6d2e213fca014c17775db460f94a4a84aac8c62f82ad4205202"),
+                false,
+            ),
+            // short padding with garbage after the digest
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("536583596568550a93cfc77a86421a6a367766a4dbb7e5aefc546c8e30f4a5b93ec8350deda71fefd03fd56f37527d80df6fd76d24c7aefadb837f634939646b2ee944f756f587ea702216a75fb19f809327e776aa5c1d2fac3a83534255a10a2c1043b081e80a3e8b0ab3e60843e749d1ce7e54ddee71e7c643775154ccee17f9d39355b3eb184b96d263033d31f1f01b82451f2dc329770af6124fe005bf94d4b444444637ee145b22897ed5d578b82af0f96c1cd243cd6d1e0b8a256ad7be0bded33f1c9bc5afe1733eb51115bb0c1013e456416038491967e8d248ab6c174e03c61ae8753077556958b4b08578beb381d9926f9a874a96fb4a3c1002922d"),
+                false,
+            ),
+        ],
+    },
+    // 3072-bit modulus, e = 65537
+    RsaTestGroup {
+        modulus: &hex!("b0c9469867ad647aebac103a28619a3e2674e44763dd2caf1206e42d368fa91b34bce5a973ec811620e435f8aa50dfaa79468b4a5c13b63f7e661a4c9309f85fc72e451bec987ec4ba1f6d9b3307548e454b2bdc9ed80e0b9c9eaefed9c8d7c61d0a57175831e0670c38baef63e42f6c829f7a3bf02a31260d7a889e93f6280936c26ad558d5e0c9af9588a100da2fcf94838df331070e900e9371c49bf0304db95855b9cede47fbd0121693f5d29ea8dc9c0e0d60310a2c25e938be46ad7b28529149ee40c90888e18fe3a32f57fadde6300147ad2e7defca05d4fdd656d8249210284d3b994b25100a7e41733f17323b931bd5031e09ffe8a333e3666c78976f29d8ab9b6e144ae1c47700b9542e3215d428cf1b44454ad179e641283f3881622b436b5a07d8af002d83a1b095d1e69d2a36a859e06d405b9030e056ae445b43835eb41947285d72e3db87f065fe2508d1df4b4f19eb828021a27359cd466138f1f771e661c97bcc9766621c5f939a9a539f9af0b7a34744de64344c82d7c7"),
+        exponent: &hex!("010001"),
+        cases: &[
+            // valid signature
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("0465b44cef3ab95a56c9b2db3433db03ba1a550776fc0dae52708a758322b67dca4aa0717a4338662deb8267a847d406216cae0573b39768b2bf246e7253f1fbeb2dfd24ab02b29562d586396759ae73f96d0e8450e40a5990d83312818309f749c1933a0cbc55d70b760d9cd0caac6437bbe78266314cac602dd397f826ef82e162942dffd81b747dee8b8077e5b1e2e34b48f7739bdc4510a182b92b8763a450c40084320fdee3136372b0286807ee01f84882d7162b29f5fbb9a1286b17aa8183d30c0adcdeef04497044a2cbddb7b9d5ba26e7038a1c52608bf198211aa748239adb85f1b6e2a8aef680f2089a8a64227c6e79d6a87585604a3d45a7c52f8b7d937fbcb0242fe15657a5f1927a6830f0593c1add6a3a21ae8db40caf8d09be4070e27be26c7b0075567efc7a9fff1fe5bdf92b017a488a8412f3e57d57bc9d8f6da1f6a299230360b168090fb5b78ba0e36a6023453b18bd8309bd9ae31ee873b8760b9f2b5f9953ce83f8788ccd7b66e85befa001b0dae3ad1fa0ba7cf7"),
+                true,
+            ),
+            // message modified
+            (
+                b"openvm-tests rsa pkcs1v15 sha256!",
+                &hex!("0465b44cef3ab95a56c9b2db3433db03ba1a550776fc0dae52708a758322b67dca4aa0717a4338662deb8267a847d406216cae0573b39768b2bf246e7253f1fbeb2dfd24ab02b29562d586396759ae73f96d0e8450e40a5990d83312818309f749c1933a0cbc55d70b760d9cd0caac6437bbe78266314cac602dd397f826ef82e162942dffd81b747dee8b8077e5b1e2e34b48f7739bdc4510a182b92b8763a450c40084320fdee3136372b0286807ee01f84882d7162b29f5fbb9a1286b17aa8183d30c0adcdeef04497044a2cbddb7b9d5ba26e7038a1c52608bf198211aa748239adb85f1b6e2a8aef680f2089a8a64227c6e79d6a87585604a3d45a7c52f8b7d937fbcb0242fe15657a5f1927a6830f0593c1add6a3a21ae8db40caf8d09be4070e27be26c7b0075567efc7a9fff1fe5bdf92b017a488a8412f3e57d57bc9d8f6da1f6a299230360b168090fb5b78ba0e36a6023453b18bd8309bd9ae31ee873b8760b9f2b5f9953ce83f8788ccd7b66e85befa001b0dae3ad1fa0ba7cf7"),
+                false,
+            ),
+            // last signature byte flipped
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("0465b44cef3ab95a56c9b2db3433db03ba1a550776fc0dae52708a758322b67dca4aa0717a4338662deb8267a847d406216cae0573b39768b2bf246e7253f1fbeb2dfd24ab02b29562d586396759ae73f96d0e8450e40a5990d83312818309f749c1933a0cbc55d70b760d9cd0caac6437bbe78266314cac602dd397f826ef82e162942dffd81b747dee8b8077e5b1e2e34b48f7739bdc4510a182b92b8763a450c40084320fdee3136372b0286807ee01f84882d7162b29f5fbb9a1286b17aa8183d30c0adcdeef04497044a2cbddb7b9d5ba26e7038a1c52608bf198211aa748239adb85f1b6e2a8aef680f2089a8a64227c6e79d6a87585604a3d45a7c52f8b7d937fbcb0242fe15657a5f1927a6830f0593c1add6a3a21ae8db40caf8d09be4070e27be26c7b0075567efc7a9fff1fe5bdf92b017a488a8412f3e57d57bc9d8f6da1f6a299230360b168090fb5b78ba0e36a6023453b18bd8309bd9ae31ee873b8760b9f2b5f9953ce83f8788ccd7b66e85befa001b0dae3ad1fa0ba7cf6"),
+                false,
+            ),
+            // signature equal to the modulus
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("b0c9469867ad647aebac103a28619a3e2674e44763dd2caf1206e42d368fa91b34bce5a973ec811620e435f8aa50dfaa79468b4a5c13b63f7e661a4c9309f85fc72e451bec987ec4ba1f6d9b3307548e454b2bdc9ed80e0b9c9eaefed9c8d7c61d0a57175831e0670c38baef63e42f6c829f7a3bf02a31260d7a889e93f6280936c26ad558d5e0c9af9588a100da2fcf94838df331070e900e9371c49bf0304db95855b9cede47fbd0121693f5d29ea8dc9c0e0d60310a2c25e938be46ad7b28529149ee40c90888e18fe3a32f57fadde6300147ad2e7defca05d4fdd656d8249210284d3b994b25100a7e41733f17323b931bd5031e09ffe8a333e3666c78976f29d8ab9b6e144ae1c47700b9542e3215d428cf1b44454ad179e641283f3881622b436b5a07d8af002d83a1b095d1e69d2a36a859e06d405b9030e056ae445b43835eb41947285d72e3db87f065fe2508d1df4b4f19eb828021a27359cd466138f1f771e661c97bcc9766621c5f939a9a539f9af0b7a34744de64344c82d7c7"),
+                false,
+            ),
+            // PKCS#1 v1.5 signature over sha1
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("2c87bf20dbde4bab16610493f8f93b52eb22686b9ac00baee9717fb96db84c8bf6de51133fbfcc608d97f153412dcef633032d94e718943b1f9744293a7f0c69fc164741b7ffe02ecd7967e92c280815ed2fc91c22b0ca512e1fec67c9e465480b4c1f303c2cc220d0350786346e4067ea107fbfc03f1ade30cb843d4b6b073c43795c07090601a05acd53cfc11fc12378d5ea7f0b95f29ceba7472baa232cc3c30673bae73fdafb3605f9071e7c7b68e3588cc5595a7954177237fed9c6b115e6b4c98e3f9013b662a516ef8a440f5146261fbd096650d4a7f29c689e15766e918f8b24305aa355f3598b14d5806d6430064911f72e9c073e781962858cf3ecb1dd6bac8e05eca7e1cf98c78e0c2eb6b5938498449d93b99ef4ef207bebe7aff52defb213035610ebc290a453fe6975e760017630edc3c0334c92bd9739d0bafd6f4eaeda3b4b47451fc2b52500ccdabaa43f4bbef1c3fa23715ce8c786eab103e96c1926ce4e3029c84d60ee15bcb96588cce339c04aa142fc1b4a9526fde4"),
+                false,
+            ),
+            // DigestInfo without NULL parameters
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("5e696f55574259784d9aa5128c0c30ef1d142c19af1197ef1a5beb2eaf6195a85413478d2b7a1d1a9c0c628c1683dfe5958ee9cec51d91dd05579b3b26c23b827095d8d6f76984f80f694bff70327d550c9785f41a4d59311259ba70a2f8cec092df0e3c825fdf756eefc61fe06210f08f71d9be2ec2b104d1935de403a8d648dfa919fe57cea1fa39f5c786e7c99f0ff82cf5f3917286b1738c47468bc8152c64d65841806f30bf5a7296a6150feadfc5404951bbb95d1c9295ff30c60dfa78937cdde6831dbfea1c58ed7e7d21e501517d830576f4a1b9c79845027550d271f8e1ae1e41096532224de89b978e7cad832492c45ab15dc90b5af7c6c6921ed9bb3a5f92892f600369014bd6384edb47d1f2455118c1de8fb230de44b24e3492380515556190943375e6f67c0147b0e46187fd6c8fba1338b8ee6fa5885706c0840659e5b7cea76a5cb10b6261ffc2bbc0004c8b9d8c7b4e2b4a2603e7fc1c3a22d01b8884451e12e454903ee0ec41480edcf4322bc8830224d806b53b04cf7e"),
+                false,
+            ),
+            // block type 0
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("4ced22a2ac7d018ae7543500cd46155ce7c722c8517c364d98e9e57656420070b35c215e6d3e239497d6e028c905d8b09750151f9e169f8ffa57572a06bf7d6dab32309e3f5c472fb8ac15fcfcd90f13c43833123d6f6227c5c6157dea7e426402e7e92f31faff7439be22ac7bf5bcca8cae68600a6ac0547372a25718311b7de10882e7fc01b0ca71a37df8160bf830470981f52d2fb50433ff0323042a88514217df64712fdf4a80db3dfa631b1b376c88afaf559a9959ab2d29218467e4bfef6c52654a09ddffa8aef0772e8eb4a34ccd486bb81a676c317500e57a7a9115546d177fcc0cd336dbc1164f3328cecbcf694ee1bcce1d0ba92dcc43dae480287e05a987fbd5a7b0e1d85887ce708035b44cf977e29273a0de8e9398fc88c5182948a31c232e0ec3c9010c34d0024574508eaedd841c1d1b8b7e20eb63618b37754ed81e33194a602fd2f19189621a3b0212f12454cf3ef1ef24326b9f230e283e5345e72ec4007673ddce7da490bd0c501b2999e586d02f0bed547afd0f34e8"),
+                false,
+            ),
+            // block type 2
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("285ba8bd81bc7036f596a89dc41d3b19b2136c3c1daec7dd838a968b3e8d94cf0f603816348ee990134e7c434fde1fc2696cb13e7348b447d4c6a341d06bf361a4da8f2b19c1424f106054f373aa23b36c9fdff626a1fdbad5d0d5db09d9e325e21198765d3f16784e958bc797516545816cbece30cd353ddce9f0f4efa3318b45b23f7e44f7b4ea98842d6fdd29c5c81465f51446d700402358d841a96290bb54c2f89cfba2241ca6acc3cc3e6fed78a8b732bf1b6813419c1e0fb890ce134420ecdcd74f4dbe82a73bdb5a74a85ce3631871f96f0bdab452e62da3a85f55aa71a1e7908e51a8f1bdea2c1f11586398baf439c21132cd1747764bb556890238d4a8b1c36094051b59a6df5fdf01dd8170d77dcaed8adfdd895ed7232676533161d9b444f9b94d6a14e276057346b10db3158c8dd0bea22d65580cf451ed9cd4688c25b5de7ae6e93783fbe25dae3acc167a5bc29560b6c9422f11ab8fe0716268420178c4b40ad49baa74babc305da4a2d5bed1ce2847b3e09336387a250aed"),
+                false,
+            ),
+            // padding byte 0xfe
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("73fa67a716888d04a6c18bb6ea52c929ff22090da744025d8a2410c33a653a13d23ba172dc00dcb708e70a0afacebb42a8510de586624a5f43ca7668bf5abf2cdda908a659b4375eedd89a79be6166bf1225607a11bc9ac9cd5870cca29c13b2491b7041064031c7cfb67ab5889d71a5554a2cc6f95188d2ba41c81c75c623d02fd0d227c82d6e4b6d431d8f4122c3db814269074816fd7b5ef8491d037369d3eda7ea9fc75f0c4efb07acf79f281698630b7abca1d3a9b9cb5ebf56e936eeedea866bb446ee495d172a2b562e9876d9438bb7a351f6ed41657d0ca207b0d6d3c1b9c96a8b0feeb444a3afdea0634f0aef0ab2720e9989ada300593f52ab40f0f0bd6298f943e5aa42321fb96fdf62db462d628ca11f69138b980c6e6885549dff0a2fc09bb7e560f8daf542ea030a415f01ca17fdb4f8e091abb5dab0d29528fe9e9530918297eb912bc2395f340967e66dce19ed16cc4b3a40755fecc3e42ff757b7e572e060a97ea4130bce28ef0a42e5cd6e4e2c1483b777cf206c860058"),
+                false,
+            ),
+            // zero separator replaced by 0xff
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("333a7820d49cf6386f96355ad604cc88bd0eeb5f1d86b36410772335576692b967d77d92abcce7ba53ccc4fefa3e6cacb98c6aa8435a3ed31506373ac0e5633dbc2b7c518159792233bffad139dc6173c49cab03078d9d552dd5593e5612baa3ae7b5034d3c5ec90067680bcefd3a60b6b7df4268679437532424bcb0fcdcaf1a35bd13d4b3011bf267c30dd376d34b7b3c88e01586cb6b3d7a540edd9c4a98fe6874d2feb498dd4e9d5aad9417fb31708a71aade210820a1315529fa0835a12265c72a2f62ef487f30b01d8ff40ae99006c807fa8390e56b077ca46136a55dcf9c6c79aa146fd6a92d66f17dd5df7b119cf4e957432403a0ad3ba5158e65f936b87ad0dff9cc9e828d06211686f4e57580b22ae19939338a86a0149a7805159f7baf1f4413536cd47fdf970b18ce3b367ea871ec1aa6d4c6d112b6c7442a0d7224d2a90e7d8a4ee6ebd35f0597b72c743c4ee7aa4c0eb2556bbdbabcb7d29b9514e276f489851e5f5d1d7d587961f1ede709e3aaec6777a818c15b4da16d678"),
+                false,
+            ),
+            // short padding with garbage after the digest
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("8e42ba323eca5b96fc254c246cb78f8c66b8d8b8ae7e84dfa3b737257dcb8cb6421ba22c22f6f223cc18917e5c339a21f89c5da3b14c068201e69f60e53b12d5d2edf7c655d1e84eabe37f4d2e5c8a08b93fd494f15d1f2ef7b168329d4cc2680ad59da4aa3c573064ad517ee1569870fd412e611e2a015d4ed6800fb69faa94afb811d45aaa4f54cbaf7cdf13a08af20cee7af8cabc8adb28b8e7f5cacaffea88bb6a0c5b8591242766ff93707e94a041f0a79e99e3c38d57085fc895d19cb06c31026c65365bb9382d52909ba124cc1426da3998150179ea86f377f9c553b94efec10b0402f6d6e09ca9f20ef35a419fb77708aa4193982a523516c0fc22663c76b310074cdc14e6a22d0d71226d232021fa656fd1fe4d396d6dd2dafe88d8c4bab57154774cec4e61ce5ea79e9f470fc2ed4cfee179e7db1ca40aaadf3c12ae95ff0432e8ad529f75ddd333ea3a5edfbe11cc526e75d4625fbc2942d85a2a4118fc8110d7f4737966f7c18d80ece113e7d737398488db41d6d4d3b906f8ba"),
+                false,
+            ),
+        ],
+    },
+    // 4096-bit modulus, e = 65537
+    RsaTestGroup {
+        modulus: &hex!("a0e1836ee42f00fc2230fc442b2b1a3057a37dcaf2177276060b4d3dcc933053822485761bbe01c9676ea1936d5c02c202e22e146627e172e1d1edb399ad4f66f9a9757bcd9212bd57ff81ea1fea9fe6211476e95c49a5b71427309723a5fd7b21a50bb6496f334c5472f2ee76a66a65a3df16b79609ac452c61d1ef4d3a74d5752f1156c0adf76feecf01fdd02997428635233d9e1b412b097854fa6a4904a68b723a699051cad629fb34a359a93bf0aed0beac4ad62f2e0d3556e3eeeeb50527934eed89516d478118856296ca90f5ce2495c300d06e849794fcaffb997006d6264a2e240485e2901e210c773714961b0ae6fafdfdc090f97f23d9ff6f0e92d4605fcf1f8fabc1a43c05cd2201d5f5665c7019e4d9a80cd7b02b2eb3154ddeb090a14a4ac1d0f3d41538659ce06485cc0366faa6b498b9bc3f05591003bbb5879233db52f6b5d81605f2a5b23983e7236642768d0d5530102d720a13f4f1e38f36b83286cc5f404371acdb7ed4be4b504f39fb6f293d3896323c72369a8693c79b936806a4a9e27c90137b3ba01bc8df2602d104777eda20767f142baf9567aafcba5e3721e6dfab0dd8713ae40ab80ef316bf8b870037b4209548b6c7667fa6232aa8c1f8053cce62c4028adf47463b67e3fe05ec98cba8da9fb79ef6e355fc208d744a864e0e7bfeff0def65286c343a8df5a4bd3d49b97b800636ff31f3"),
+        exponent: &hex!("010001"),
+        cases: &[
+            // valid signature
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("543b0cff1f6a2c4420d5579be803c59db9028bb5af08adb67b1821a4f40e7ab10cfd1d464e826185ceaeb39220d6911b6e44df908e13aac71c30ecbcb88ab4f2b0b216940649394cf7fb3d24e2e855b8cbd839a35e48a5fe62d920b940b3e17de2f5e5597982e5a3f7b740f86a5942418b18d399e9d8479f615013333e15ed31dbcfeb697d9c2d6ae62f2dbbe7ec5a71bdabc0ab652ce6507c1b63ce4dcd123766ddf42b95e2bbbafd07fb6d7ab9bd5251f636edb643526698052ba551fbd68d46c3a14db62d40a52f9575807b9dbe032194f148e7a44a57b1b15c351606868bc2734287c0a46d6d25939cc2ed59abfbe32791911c3a1b13c08f9fc65a1c7c6239ac40ccdd98302daea59be1aa98dd1eb728eeb1400a39b16af355f7aacabe9fd8577f5735e0d5b2edd5c2c5c7759bce529a053f9ca00d66a264344f6d4ac85710f2d91f914d227278cae1c2247b3bcb0fb9e0a23a6bd1702399ce3d3f5b01f630249679e61165b913efe89c24d9f6b452f9c9fddf2efec0455b4dcc0fac7d57c501d307f55c25092b09d918687bf1833392402a4a9fd1f1347bdd9d7fde8e1b50f7256302cebe7ab643c7739460c1826bd6a0106b0570cd333dab748401f1e8390f2444d1c731a77284f3c53e598d5800f9fbe6f9e16b7c56f8952897d5c71c4f934c8f52c0b5a55bcd0af958df208319319c8f7b2edaa3cf25e99b8144accc"),
+                true,
+            ),
+            // message modified
+            (
+                b"openvm-tests rsa pkcs1v15 sha256!",
+                &hex!("543b0cff1f6a2c4420d5579be803c59db9028bb5af08adb67b1821a4f40e7ab10cfd1d464e826185ceaeb39220d6911b6e44df908e13aac71c30ecbcb88ab4f2b0b216940649394cf7fb3d24e2e855b8cbd839a35e48a5fe62d920b940b3e17de2f5e5597982e5a3f7b740f86a5942418b18d399e9d8479f615013333e15ed31dbcfeb697d9c2d6ae62f2dbbe7ec5a71bdabc0ab652ce6507c1b63ce4dcd123766ddf42b95e2bbbafd07fb6d7ab9bd5251f636edb643526698052ba551fbd68d46c3a14db62d40a52f9575807b9dbe032194f148e7a44a57b1b15c351606868bc2734287c0a46d6d25939cc2ed59abfbe32791911c3a1b13c08f9fc65a1c7c6239ac40ccdd98302daea59be1aa98dd1eb728eeb1400a39b16af355f7aacabe9fd8577f5735e0d5b2edd5c2c5c7759bce529a053f9ca00d66a264344f6d4ac85710f2d91f914d227278cae1c2247b3bcb0fb9e0a23a6bd1702399ce3d3f5b01f630249679e61165b913efe89c24d9f6b452f9c9fddf2efec0455b4dcc0fac7d57c501d307f55c25092b09d918687bf1833392402a4a9fd1f1347bdd9d7fde8e1b50f7256302cebe7ab643c7739460c1826bd6a0106b0570cd333dab748401f1e8390f2444d1c731a77284f3c53e598d5800f9fbe6f9e16b7c56f8952897d5c71c4f934c8f52c0b5a55bcd0af958df208319319c8f7b2edaa3cf25e99b8144accc"),
+                false,
+            ),
+            // last signature byte flipped
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("543b0cff1f6a2c4420d5579be803c59db9028bb5af08adb67b1821a4f40e7ab10cfd1d464e826185ceaeb39220d6911b6e44df908e13aac71c30ecbcb88ab4f2b0b216940649394cf7fb3d24e2e855b8cbd839a35e48a5fe62d920b940b3e17de2f5e5597982e5a3f7b740f86a5942418b18d399e9d8479f615013333e15ed31dbcfeb697d9c2d6ae62f2dbbe7ec5a71bdabc0ab652ce6507c1b63ce4dcd123766ddf42b95e2bbbafd07fb6d7ab9bd5251f636edb643526698052ba551fbd68d46c3a14db62d40a52f9575807b9dbe032194f148e7a44a57b1b15c351606868bc2734287c0a46d6d25939cc2ed59abfbe32791911c3a1b13c08f9fc65a1c7c6239ac40ccdd98302daea59be1aa98dd1eb728eeb1400a39b16af355f7aacabe9fd8577f5735e0d5b2edd5c2c5c7759bce529a053f9ca00d66a264344f6d4ac85710f2d91f914d227278cae1c2247b3bcb0fb9e0a23a6bd1702399ce3d3f5b01f630249679e61165b913efe89c24d9f6b452f9c9fddf2efec0455b4dcc0fac7d57c501d307f55c25092b09d918687bf1833392402a4a9fd1f1347bdd9d7fde8e1b50f7256302cebe7ab643c7739460c1826bd6a0106b0570cd333dab748401f1e8390f2444d1c731a77284f3c53e598d5800f9fbe6f9e16b7c56f8952897d5c71c4f934c8f52c0b5a55bcd0af958df208319319c8f7b2edaa3cf25e99b8144accd"),
+                false,
+            ),
+            // signature equal to the modulus
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("a0e1836ee42f00fc2230fc442b2b1a3057a37dcaf2177276060b4d3dcc933053822485761bbe01c9676ea1936d5c02c202e22e146627e172e1d1edb399ad4f66f9a9757bcd9212bd57ff81ea1fea9fe6211476e95c49a5b71427309723a5fd7b21a50bb6496f334c5472f2ee76a66a65a3df16b79609ac452c61d1ef4d3a74d5752f1156c0adf76feecf01fdd02997428635233d9e1b412b097854fa6a4904a68b723a699051cad629fb34a359a93bf0aed0beac4ad62f2e0d3556e3eeeeb50527934eed89516d478118856296ca90f5ce2495c300d06e849794fcaffb997006d6264a2e240485e2901e210c773714961b0ae6fafdfdc090f97f23d9ff6f0e92d4605fcf1f8fabc1a43c05cd2201d5f5665c7019e4d9a80cd7b02b2eb3154ddeb090a14a4ac1d0f3d41538659ce06485cc0366faa6b498b9bc3f05591003bbb5879233db52f6b5d81605f2a5b23983e7236642768d0d5530102d720a13f4f1e38f36b83286cc5f404371acdb7ed4be4b504f39fb6f293d3896323c72369a8693c79b936806a4a9e27c90137b3ba01bc8df2602d104777eda20767f142baf9567aafcba5e3721e6dfab0dd8713ae40ab80ef316bf8b870037b4209548b6c7667fa6232aa8c1f8053cce62c4028adf47463b67e3fe05ec98cba8da9fb79ef6e355fc208d744a864e0e7bfeff0def65286c343a8df5a4bd3d49b97b800636ff31f3"),
+                false,
+            ),
+            // PKCS#1 v1.5 signature over sha1
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("4237981be8c1b831d80fabc37ea09a51a4b64503c22bf692617d4a043bbc95d592ff4ec2d3bd2a1b5c931666891c647f9f7255e358f5131c41163a494011465fbd05d0eeee51bad145781e918595bc1a0205a9c003cb86669587edbedbc24cb68f74d0e180c6f1b9a1f6574bd16f9bd3be219eb42aca4edd7ad606345228eb4582d30fa3e80c95506a5bf94d82de660416693b8cbc3073918f7dce5f8472faf68ae71808047f9f7bc0985a5c196de981522c41eeafe9e029a8408deee5c83375fdff8a2be31b9c1308d7637875384a3049997f0ff71168df1f29bbcb115c02273bf2e7d9720ca1da90b48686da917318fcc8604a36e9096ae4586107389ae8b3554e5e5320dba5b51b64e88cdf220eb4f90006363c11a75e6ca99a6856050ef16103f0e03e9c7d7e7893e05ea4304892fd5838338e6698de31958ebf17a08e6c6227f39d355ca38eaf84db0ef3a7f69f4f8428f2b445d362891abe577b908beec7f2693261c482139aaac98a654b82e031e6cffe7627e7e905d6cf52e14dc29f3ea9a83d8dd997dd9af7343646b666725791f8a4dfa0294a727fa59d389a07123f6f43d0da7f610bf8c3f072354cd235447a72ffbc1e84cfa5b9c0312113ea278938238a21d4f003f51b6028782cab718c65216dd54625c348532adf3c71bc478a0ec8ca69612aacf9c8550272bc2df5f2c17b6adfa845fb252e0efc64de7506"),
+                false,
+            ),
+            // DigestInfo without NULL parameters
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("32356a7a03523d116c1c278d91da71a042e65e35f997f603095f2dbaa7b32a7ba851396b0501327f78c7f75c2c81d33bb1f82acc03abaa94adbb324647361f66e79809d9b524e35c17b4f920c570e8e0d6d16531dfcf728c8104098ca5ebe0ddb4a03a722c231cd7d385d65acf72691007d08f0feaf13706ae2a45e78c23fc4e4385cad5974c0bd59ac0a34744d33ac32b20f7510d6749ef34bfaefdffe5bfcb5888fd3728d3ba0295218391b0e83fb6962ee2ca102dc7a9231b483ce064bfdfff3372cbbe6bd8764567a00dd0697bc41bc50e576182202b01458b2a354354953bc59e95ebf94b4a69f4296be13b3849d1f6e9d7d83d492fec57553fed6c8b16d67849039c9b3b97886ab6313f976b5d2baab19edba1c7ba39fb93c2170af5b5423c1621ef605aece2a86819e69bd298ecf0ce47f395bb2cedc5f38ae2cd7152eec6152515f5afca76ed07fffbcde0d5e84b7f71ecce0370f8633f6932b42827e35c9edf0500dbca5493b5d23354e0ee72d2a523e48af8f7639768e1237d698f287853ce0f14d43ee1d289eb0afd2fb96515be317d91181f6bbd13a392cd62016774cb9b3a54d9849a3195c26fa1fe616123f45b9d05bf2b37c29745d1f460a524fe495930bb30d3d59709af80933f76db9109cf0fce2a7a94e65236ed8f102eefe2eb241ff72da9bc78f1b2f2fd22ef7f1ced889e9facbef67272bfd78c0ecc"),
+                false,
+            ),
+            // block type 0
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("9e3d4477f37ddc8bfef7a38eb75f58b7188a4515aa28b05bce591e162c68c3afa80c28cd5814d035323ef252cb89c6b6243c207c980ed38ebb8aecba932083fe4576d594b10e9533ece5be148e835d45f3ac7d84bfd12152e94eb9b57fb8a797316d25243222d5587e3be4166dd41eca190d0073384ce361c00d503e1faddef8035560765d8ae1511c27dacb9d448062b49e3c3d754dc9118c6cf1ed0e985dd1f57746da7c1a16ec4fc15efb022ca3ff4471fd1d1f893cceefac8e319e262edf7a72ed46038080b0514801d2179c24cca8db443878688a158c3f5af757f9920c7dea5af0384eab3c113aee399881fcc3988145068c15d8df7650b44e0d3c335a0e18ebeea4171c05848381269daeb0a82e4bd548ac0046c761016439be5a37c9b1debec3e4473c1cc403586f40a33bcaf437d8f6d7c8b65f43afdc49136bd6155dfa60da1f7bed6fd1e51a144d705993e537ecfed645df6cd0594afac24eabaed072f65b09d1dafa1e122deaa7b7304292e2941cc15af06a8e7085d241251ca1313282f2f83e80396fc2b24bbdb1401a201989dfc40b98e14559794f0d69148ada10c5fcb5352d6c54d95ae51c951a5c886bfec6296e650bf6564318bdfc43d071d6d6e4a32ec77bb90e1d0f338688438a6648c58ed8f5c3d751c78720570110548c3744f79c6af3ad683fe654dca2aa587853c5db314e2562b42cbf8be9f4bd"),
+                false,
+            ),
+            // block type 2
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("0ccd4eaf6664565a64ba4edd6d84c262f838034efb46a6e1c3e4ff4298c68038745aa28df2b2e7691906bf6f134a2c64dab3c5bee2cd6ac969618d62c97a18df4618e40653764c8ec79efc7cc49e82f0e667327f5827da6805c33c77cfbf7f4b15556faf31d3ea5fe68ecbf5beaea8761da491541f734882862112a3e372ee2f5b03b0adb7095e6b868ec758a60b10257bc5b05083444686579f6e64b8a6bdfd70a991d9e900e703619b5ae3a6d3ad65a5993715c3b118e8fe4933c37ef025967d570453bac797215f5707db2c329988c09bef3d543e23119d39afdfbf6777ed5c938b8d863d08c961114ef92d15d2c444fa0cc67aa0860ed0b1027155457ac6ab2d802401dc7822cf01e6b0356e3053614402deb11f226591ca4e9ce6a3844f7acd38a17f0a852a08ed0a10b853f773dd715b59b03dbad63d666c69f6cfc139e8d7a577bbeaabd37525cf48316b7f3dee2f0508a1a95b90c43c0da4e092ff30135d1da60667f37f64cf02ef5303b387fd97a85ab933d99c3201bc65f85be2f69c201f68aec920d6145913030538227ec9cf478d013bf6bdae3bfc7728b75b83f0ee5865be06ead251afcd5f5f2831e0d868b3b4a4ab5904107eb65aeb1f52363225a2ba9ee3685b67b929cd442d19b9c4de140f9adb8030661cdf4beaff0fd9fc2e65a1877ac71c48be02bbeae3fd04037b2f42f2120d8b2bb7fbdb1e2fa17a"),
+                false,
+            ),
+            // padding byte 0xfe
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("50b6a37ff4f6c2560a137773b2852614a675f3b9647c98275ce5c3fe831e2326a3e4555a1246337e4fd1bbcfc2be4eb71fb22a136c7d942c72f2643e0481d605503afe8a9442e5e18a1bd10d984098bade6db3d3833f06185c6db970fdef84e775473284428eeb07eedd2d52a301bc150a37147212e0f0244e40b7f4b256233dffdeca024b8cef639077c9e78bf05fa9a1b3af12409c9794cf8713b3d061acff3136f548bebdd97c4c10271939d337880f0c1edf4634f03afdf6028c421f3ebac2b5d0eeab4d052bfd2a74a2223e180f2160a301c28009c4191f7306a1853914e00e1ef451967a9bc8d8d4b5bfa34b20cf43afea01ae72fa1fe73d6b20a5309db20108efda00778b969aea526c2315fb0581707a9155f971b10623a175d30aed024eac0d55e48143182cb4b3589656d71e4fd318ea9447c2c3de282b9d49973c461172295e97efea0191ed378e4afb707805c9d82e58d9b1d125f54fce2011fb5f84e7ba8b170ef252626e19755feccb80097db4450cf5d24b8de39554e2250da5d5b204f976d33d0cf5765a4b75ecd99ef7db163564222babeb1413a3bf82e473818f931657be686800446a01e7fe4731396adc9b2f44fadd8b1298e37920bebae13a3b2017420a4f9b41d6a6fecb130c07febe7757ce6ffd03a52f432adf7f369a16af66fc9d797bbf82ad650b40cbe36573b1c9fd5851b8db28345596ad13"),
+                false,
+            ),
+            // zero separator replaced by 0xff
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("99a95c50fa021fef6e07215fc76c2516d079257740fe3c4fd06355b4913a44143fcd7439c34102862298416749203a78c97c868d978046673a114536c45bb5cb2bab62cad7907cdef03f2f1ca598f47241851cd272957f99eb8959583468e32b8f9d10e3281388658ca6bbb147aa8d1479b62ecb962674e5a3ab16d1012e0ab82c28aedee160481d02f3d92100656a88136666049dc64d22027590d107f286a8122af8917a877dd5847d5d7b1174e620b9f714aa52c92672706e6cd57b0e48698715868980c4324ea5441df60a5cc89aee907b837fcde4f5f383aa833d61eb1a420c857572a2855a349fb7869a0bcd727e0912cc9021b73f02cb50b1ec28192739c97d719046a1ba94ef87e600aaeaa5a5a823f510402bb2d9e8a2817f4e42588b4babb441cde5eaeae09a32c16a4eac847f2f2c2969ee4fc53d00f883fbd6b3f4f33e15dc5855f117f1cfa4d33d8c86a55a3950e0a5d49f7d10242fcc6f69fcd37d3978e976c9b0951e363833eb09440b072127becf30acd73645f9cc08b44e5796861cfb122e04c434883d2a90b01d980a84e4cfd8591b36e4e0107ff4a0beadf3f4f5a643e288792e10bdbd8b8b8524a56569b275cdbd8e381315d6686e9d011491ba4bd88e5fed0a79ef6cf2e41722998af7b8f82a52cf3e8ce7401343b2c652609d8fcd8922bf8b03f9cf7b465814e32925c2f37e0a9417e2bafa86c227"),
+                false,
+            ),
+            // short padding with garbage after the digest
+            (
+                b"openvm-tests rsa pkcs1v15 sha256",
+                &hex!("549965215f932a4a98dd6c89b182b23816202bba0a7582aad04b406e9a1ce2c2b09b6fd47409989f702729a3f3579f642dc3fdffc280a8408fb814050aaee60c32b2d1949682bb96650e911cea6eb396291ad0251ce70826375e331eccd3df2df64b98dfb81e38d264893adbafedd0fb11ce697ebba14d7641a9f2d52b5454e012f8d2c6610d50b6d3ebb943986d9accf0a12c7c87b80adee479b21efbc44dc86de3cae4f8b9ff6def524f1bcb2f1cad6941ad9729998e4c18cb3af41a4d8738b4e2e5ee80e6fd3259b9d4c1ff495103362534d4402eaa46d7332ed32ea1fe550e2e4754a0aa97a2f7a1c4d0a7489eb86d4bb6e7868eea7daec19342e21d4120cb968696f7716bb9886ac44068d4a80deaf6a243e34bc633e092d91644038e4ad0fb9c617dcdb80fef49a44b01bfe0124382bbd285a0d42457f4401995d3c38597cd3fb39acae8e560756f4e6e526f138e06d8f81c819e8ad8a195fab20bfa56dd3f7e6a1b00f9adb07682b33825baaa581e71a8f25d19ddfb82b1a2dd6cf0645f6ea508636e9a7f2f0a455a80b0bf82d5083fb69b003a6c7bb12a73673d898b635cc607bc8165cffb5a6848c103d603489eae9b7e002f9920f4a4db81807fb9344fdfe8da4b9469db2f4d4dcab544d491c5a2cfb99084550e7141b082a5e1d9b3ef9419bc8b64f5a25fc5bcebfd990452904a4660541cbc82ec071771499ccb"),
+                false,
+            ),
+        ],
+    },
+];
+
+/// Run RSA signature verification tests, with cycle markers per key size and case
+pub fn run_rsa_tests() {
+    for group in RSA_TEST_GROUPS {
+        let public_key = RsaPublicKey {
+            modulus: group.modulus,
+            exponent: group.exponent,
+        };
+        let exponent = group
+            .exponent
+            .iter()
+            .fold(0u64, |value, &byte| (value << 8) | byte as u64);
+        for (index, (message, signature, expected)) in group.cases.iter().enumerate() {
+            let label = format!("rsa-{} e = {exponent} #{index}", group.modulus.len() * 8);
+            cycle_tracker::start(&label);
+            let verified = verify_rsa_pkcs1v15_sha256(&public_key, message, signature);
+            cycle_tracker::end(&label);
+
+            assert_eq!(verified, *expected, "{label}");
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use openvm::io::read_vec;
+
+use crate::{
+    cycle_tracker,
+    rsa::{verify_rsa_pkcs1v15_sha256, RsaPublicKey},
+};
+
+/// Wycheproof test result
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum WycheproofResult {
+    Invalid,
+    Valid,
+    /// legacy encodings a verifier may accept; the verifier is strict, so these may go either way
+    Acceptable,
+}
+
+impl WycheproofResult {
+    /// Decode the expected result byte written by `scripts/wycheproof_rsa_inputs.py`
+    fn read() -> Self {
+        match read_vec().as_slice() {
+            [0] => Self::Invalid,
+            [1] => Self::Valid,
+            [2] => Self::Acceptable,
+            other => panic!("invalid expected result {other:?}"),
+        }
+    }
+}
+
+/// Run Wycheproof rsa_signature sha256 vectors read from stdin, see `scripts/wycheproof_rsa_inputs.py`.
+/// The input stream holds the number of test groups, then for each group the modulus, the public
+/// exponent and the number of tests, and for each test its tcId, message, signature and expected result.
+/// Counts and tcIds are 4-byte little-endian. Cycles are reported for every verification.
+pub fn run_rsa_wycheproof_tests() {
+    for _ in 0..read_u32() {
+        let modulus = read_vec();
+        let exponent = read_vec();
+        let public_key = RsaPublicKey {
+            modulus: &modulus,
+            exponent: &exponent,
+        };
+
+        for _ in 0..read_u32() {
+            let tc_id = read_u32();
+            let message = read_vec();
+            let signature = read_vec();
+            let expected = WycheproofResult::read();
+
+            let label = format!("rsa-{} wycheproof tcId {tc_id}", modulus.len() * 8);
+            cycle_tracker::start(&label);
+            let verified = verify_rsa_pkcs1v15_sha256(&public_key, &message, &signature);
+            cycle_tracker::end(&label);
+
+            match expected {
+                WycheproofResult::Valid => assert!(verified, "{label} must verify"),
+                WycheproofResult::Invalid => assert!(!verified, "{label} must be rejected"),
+                WycheproofResult::Acceptable => {}
+            }
+        }
+    }
+}
+
+fn read_u32() -> u32 {
+    let bytes = read_vec();
+    u32::from_le_bytes(bytes.try_into().expect("count must be 4 bytes"))
+}
@@ -3,7 +3,8 @@ use revm_precompile::{hash::sha256_run, Bytes};
 
 /// Vector of test cases for SHA-256 hash function.
 /// Each test case consists of (test_name, input_bytes, expected_hash_result).
-const SHA256_TEST_CASES: &[(&[u8], [u8; 32])] = &[
+/// CAVP entries are printed by `scripts/cavp_sha256_cases.py`; SHA256LongMsg.rsp is not yet imported.
+pub(crate) const SHA256_TEST_CASES: &[(&[u8], [u8; 32])] = &[
     // "ethereum/tests/Hash function SHA256"
     (
         &hex!("0000000ccccccccccccccccccccccccccccccccccccccccccccccccccc000000"),
@@ -39,13 +40,195 @@ const SHA256_TEST_CASES: &[(&[u8], [u8; 32])] = &[
         &hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
         hex!("af9613760f72635fbdb44a5a0a63c39f12af30f950a6ee5c971be188e89c4051"),
     ),
+    // "NIST CAVP SHA256ShortMsg.rsp#Len=8"
+    (
+        &hex!("d3"),
+        hex!("28969cdfa74a12c82f3bad960b0b000aca2ac329deea5c2328ebc6f2ba9802c1"),
+    ),
+    // "NIST CAVP SHA256ShortMsg.rsp#Len=16"
+    (
+        &hex!("11af"),
+        hex!("5ca7133fa735326081558ac312c620eeca9970d1e70a4b95533d956f072d1f98"),
+    ),
+    // "NIST CAVP SHA256ShortMsg.rsp#Len=24"
+    (
+        &hex!("b4190e"),
+        hex!("dff2e73091f6c05e528896c4c831b9448653dc2ff043528f6769437bc7b975c2"),
+    ),
+    // "NIST CAVP SHA256ShortMsg.rsp#Len=32"
+    (
+        &hex!("74ba2521"),
+        hex!("b16aa56be3880d18cd41e68384cf1ec8c17680c45a02b1575dc1518923ae8b0e"),
+    ),
+    // "NIST CAVP SHA256ShortMsg.rsp#Len=40"
+    (
+        &hex!("c299209682"),
+        hex!("f0887fe961c9cd3beab957e8222494abb969b1ce4c6557976df8b0f6d20e9166"),
+    ),
+    // "NIST CAVP SHA256ShortMsg.rsp#Len=48"
+    (
+        &hex!("e1dc724d5621"),
+        hex!("eca0a060b489636225b4fa64d267dabbe44273067ac679f20820bddc6b6a90ac"),
+    ),
+    // "NIST CAVP SHA256ShortMsg.rsp#Len=56"
+    (
+        &hex!("06e076f5a442d5"),
+        hex!("3fd877e27450e6bbd5d74bb82f9870c64c66e109418baa8e6bbcff355e287926"),
+    ),
+    // "NIST CAVP SHA256ShortMsg.rsp#Len=64"
+    (
+        &hex!("5738c929c4f4ccb6"),
+        hex!("963bb88f27f512777aab6c8b1a02c70ec0ad651d428f870036e1917120fb48bf"),
+    ),
+    // "NIST CAVP SHA256ShortMsg.rsp#Len=72"
+    (
+        &hex!("3334c58075d3f4139e"),
+        hex!("078da3d77ed43bd3037a433fd0341855023793f9afd08b4b08ea1e5597ceef20"),
+    ),
+    // "FIPS 180-2 one-block message"
+    (
+        b"abc",
+        hex!("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
+    ),
+    // "FIPS 180-2 two-block message"
+    (
+        b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+        hex!("248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"),
+    ),
+    // 55 bytes, the longest message padded into a single block
+    (
+        &hex!("0a3898817b165ec15dc8dd5d7a3a61c8af36d419e1dab26b3d98a43f841b57e561cd66bb24473a61c1e72e02015346a4991bd598cab60e"),
+        hex!("e2563274a74937e79a6407e2a5722d6924a0a59f43a99eb5650832cecc57be85"),
+    ),
+    // 56 bytes, the shortest message whose padding spills into a second block
+    (
+        &hex!("0a3898817b165ec15dc8dd5d7a3a61c8af36d419e1dab26b3d98a43f841b57e561cd66bb24473a61c1e72e02015346a4991bd598cab60eb8"),
+        hex!("d83e4c6aa293e367935f6f50d2a5d25da3e774ee105f99f7f34d77a70fbf0dec"),
+    ),
+    // 63 bytes, one byte short of a block
+    (
+        &hex!("0a3898817b165ec15dc8dd5d7a3a61c8af36d419e1dab26b3d98a43f841b57e561cd66bb24473a61c1e72e02015346a4991bd598cab60eb8edcc97e6b24a39"),
+        hex!("b00903ea7ffbf97a80017dc7ebb3f886d1579987c30fe3d60de92f18e8857116"),
+    ),
+    // 64 bytes, exactly one block
+    (
+        &hex!("0a3898817b165ec15dc8dd5d7a3a61c8af36d419e1dab26b3d98a43f841b57e561cd66bb24473a61c1e72e02015346a4991bd598cab60eb8edcc97e6b24a3917"),
+        hex!("d2da6de5e568c148bde0fce33a6ae2fd58a763ce35458e629ccfbd9665fdde5e"),
+    ),
+    // 65 bytes, one byte into the second block
+    (
+        &hex!("0a3898817b165ec15dc8dd5d7a3a61c8af36d419e1dab26b3d98a43f841b57e561cd66bb24473a61c1e72e02015346a4991bd598cab60eb8edcc97e6b24a391706"),
+        hex!("609f35c5dbc7b999d2a3e9b9b4c2f9d8b293070f963d44d9540fea6247627753"),
+    ),
+    // 119 bytes, the longest message padded into two blocks
+    (
+        &hex!("0a3898817b165ec15dc8dd5d7a3a61c8af36d419e1dab26b3d98a43f841b57e561cd66bb24473a61c1e72e02015346a4991bd598cab60eb8edcc97e6b24a391706aaa4aebe8a31af6940fc4b4c76a8302343ee76ef2c36186e54a89fa7d6e40befc59307ecc9b6f007ed147ebe9e27777bb2e87773eed4"),
+        hex!("38dbd8a7a36646af4b94250d260e037eade56afcccd8719432ae21a06de40645"),
+    ),
+    // 120 bytes, the shortest message whose padding spills into a third block
+    (
+        &hex!("0a3898817b165ec15dc8dd5d7a3a61c8af36d419e1dab26b3d98a43f841b57e561cd66bb24473a61c1e72e02015346a4991bd598cab60eb8edcc97e6b24a391706aaa4aebe8a31af6940fc4b4c76a8302343ee76ef2c36186e54a89fa7d6e40befc59307ecc9b6f007ed147ebe9e27777bb2e87773eed4f4"),
+        hex!("341353fbc44fd3019908f7eab315dd35355c105b0417942963b53f704515f55d"),
+    ),
+];
+
+/// Vector of test cases for SHA-256 on long inputs built by repeating a pattern.
+/// Each test case consists of (pattern, repetitions, expected_hash_result).
+const SHA256_REPEATED_TEST_CASES: &[(&[u8], usize, [u8; 32])] = &[
+    // 4096 bytes
+    (
+        b"0123456789abcdef",
+        256,
+        hex!("929b11f47a02202e710632002203f7ea8dd3c1bc51ef818e59b6a3cd1dc2d5dc"),
+    ),
+    // 8600 bytes
+    (
+        b"The quick brown fox jumps over the lazy dog",
+        200,
+        hex!("a986de70f55b4ea4e3460d7c7d834bb53d5620a317f176f9eb08d0eaf9c23021"),
+    ),
+    // FIPS 180-2 long message, one million repetitions of "a"
+    (
+        b"a",
+        1_000_000,
+        hex!("cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0"),
+    ),
 ];
 
+/// Seed of NIST CAVP SHA256Monte.rsp
+#[cfg(feature = "sha256-monte-carlo")]
+const SHA256_MONTE_CARLO_CAVP_SEED: [u8; 32] =
+    hex!("6d1e72ad03ddeb5de891e572e2396f8da015d899ef0e79503152d6010a3fe691");
+/// Last checkpoint (COUNT = 99) of NIST CAVP SHA256Monte.rsp
+#[cfg(feature = "sha256-monte-carlo")]
+const SHA256_MONTE_CARLO_CAVP_LAST_CHECKPOINT: [u8; 32] =
+    hex!("6a912ba4188391a78e6f13d88ed2d14e13afce9db6f7dcbf4a48c24f3db02778");
+/// sha256 of the 100 concatenated checkpoints of NIST CAVP SHA256Monte.rsp, computed in Python
+#[cfg(feature = "sha256-monte-carlo")]
+const SHA256_MONTE_CARLO_CAVP_COMMITMENT: [u8; 32] =
+    hex!("c88a5d3db7b0fdd2831d4fcda7766bd2a10b80154e2389e0189899c97a120147");
+/// Number of checkpoints of the Monte Carlo test
+#[cfg(feature = "sha256-monte-carlo")]
+const SHA256_MONTE_CARLO_CHECKPOINTS: usize = 100;
+/// Number of chained hashes between two checkpoints of the Monte Carlo test
+#[cfg(feature = "sha256-monte-carlo")]
+const SHA256_MONTE_CARLO_ITERATIONS: usize = 1000;
+
 /// Run all SHA-256 hash function test cases
 pub fn run_sha256_tests() {
     for (input, expected) in SHA256_TEST_CASES {
-        let input = Bytes::from_static(input);
-        let outcome = sha256_run(&input, u64::MAX).unwrap();
-        assert_eq!(outcome.bytes.as_ref(), expected.as_slice());
+        run_sha256_test_case(Bytes::from_static(input), expected);
+    }
+    for (pattern, repetitions, expected) in SHA256_REPEATED_TEST_CASES {
+        run_sha256_test_case(Bytes::from(pattern.repeat(*repetitions)), expected);
+    }
+}
+
+/// Run the SHA-256 precompile on an input and check its output and gas cost
+fn run_sha256_test_case(input: Bytes, expected: &[u8; 32]) {
+    let outcome = sha256_run(&input, u64::MAX).unwrap();
+    assert_eq!(outcome.bytes.as_ref(), expected.as_slice());
+
+    // 60 gas plus 12 gas per 32-byte word
+    let words = input.len().div_ceil(32) as u64;
+    assert_eq!(outcome.gas_used, 60 + 12 * words);
+}
+
+/// Run the NIST CAVP SHA-256 Monte Carlo test on a 32-byte seed read from stdin.
+/// The checkpoints are committed as public output through their sha256 digest, revealed as
+/// 8 little-endian u32 public values, which fits the default of 32 public value bytes.
+#[cfg(feature = "sha256-monte-carlo")]
+pub fn run_sha256_monte_carlo_test() {
+    let seed: [u8; 32] = openvm::io::read_vec()
+        .try_into()
+        .expect("Monte Carlo seed must be 32 bytes");
+
+    let mut checkpoint = seed;
+    let mut checkpoints = Vec::with_capacity(SHA256_MONTE_CARLO_CHECKPOINTS * 32);
+    for _ in 0..SHA256_MONTE_CARLO_CHECKPOINTS {
+        // MD[i] = SHA256(MD[i - 3] || MD[i - 2] || MD[i - 1]), starting from MD[0..3] = seed
+        let mut digests = [checkpoint; 3];
+        for _ in 0..SHA256_MONTE_CARLO_ITERATIONS {
+            let message = Bytes::from(digests.concat());
+            let outcome = sha256_run(&message, u64::MAX).unwrap();
+            digests = [
+                digests[1],
+                digests[2],
+                outcome.bytes.as_ref().try_into().unwrap(),
+            ];
+        }
+        checkpoint = digests[2];
+        checkpoints.extend_from_slice(&checkpoint);
+    }
+
+    let commitment = openvm_sha256_guest::sha256(&checkpoints);
+    for (i, word) in commitment.chunks_exact(4).enumerate() {
+        let word = u32::from_le_bytes(word.try_into().unwrap());
+        openvm::io::reveal_u32(word, i);
+    }
+
+    if seed == SHA256_MONTE_CARLO_CAVP_SEED {
+        assert_eq!(checkpoint, SHA256_MONTE_CARLO_CAVP_LAST_CHECKPOINT);
+        assert_eq!(commitment, SHA256_MONTE_CARLO_CAVP_COMMITMENT);
     }
 }
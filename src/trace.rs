@@ -0,0 +1,97 @@
+use openvm::io::read_vec;
+use revm_precompile::{PrecompileError, PrecompileErrors, PrecompileSpecId, Precompiles};
+use revm_primitives::{hex, Address, Bytes, Env};
+use serde::Deserialize;
+
+use crate::cycle_tracker;
+
+/// Error string geth reports for a precompile call that ran out of gas
+const OUT_OF_GAS: &str = "out of gas";
+
+/// One recorded precompile call, a line of the trace JSONL.
+/// Hex fields are 0x-prefixed, gas values are decimal.
+#[derive(Deserialize)]
+struct TraceCall {
+    /// spec whose precompile set the call runs under, e.g. `"CANCUN"`
+    spec: String,
+    address: String,
+    input: String,
+    /// output of a successful call, ignored when `error` is set
+    #[serde(default)]
+    expected_output: String,
+    /// gas made available to the call
+    gas: u64,
+    /// gas charged by a successful call, checked when present
+    #[serde(default)]
+    gas_used: Option<u64>,
+    /// geth error of a failed call, `"out of gas"` must map to `OutOfGas`
+    #[serde(default)]
+    error: Option<String>,
+}
+
+/// Replay recorded precompile calls read from stdin as JSONL, see `scripts/trace_inputs.py`
+/// and `scripts/geth_trace_extract.py`, through the precompile bound at the call's address
+/// under the call's spec, checking outputs, gas and failures.
+/// Cycles are reported for the whole replay.
+pub fn run_trace_tests() {
+    let trace = String::from_utf8(read_vec()).expect("trace must be utf-8");
+    let calls: Vec<TraceCall> = trace
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).expect("invalid trace line"))
+        .collect();
+
+    cycle_tracker::start("precompile trace replay");
+    for (index, call) in calls.iter().enumerate() {
+        let precompiles = Precompiles::new(precompile_spec(&call.spec));
+        let address: Address = call.address.parse().expect("invalid address");
+        let input = Bytes::from(hex::decode(&call.input).expect("invalid input hex"));
+        let precompile = precompiles.get(&address).unwrap_or_else(|| {
+            panic!(
+                "trace call {index}: no precompile at {address} under {}",
+                call.spec
+            )
+        });
+        let result = precompile.call_ref(&input, call.gas, &Env::default());
+
+        match &call.error {
+            None => {
+                let output =
+                    result.unwrap_or_else(|error| panic!("trace call {index} failed: {error:?}"));
+                let expected = hex::decode(&call.expected_output).expect("invalid output hex");
+                assert_eq!(output.bytes.as_ref(), expected, "trace call {index}");
+                if let Some(gas_used) = call.gas_used {
+                    assert_eq!(output.gas_used, gas_used, "trace call {index} gas");
+                }
+            }
+            Some(error) => {
+                let Err(actual) = result else {
+                    panic!("trace call {index} should fail with {error}");
+                };
+                if error == OUT_OF_GAS {
+                    assert_eq!(
+                        actual,
+                        PrecompileErrors::Error(PrecompileError::OutOfGas),
+                        "trace call {index}"
+                    );
+                }
+            }
+        }
+    }
+    cycle_tracker::end("precompile trace replay");
+}
+
+/// Parse the spec name of a trace line.
+/// These are the precompile spec ids of this revm fork; it has none for Osaka.
+fn precompile_spec(name: &str) -> PrecompileSpecId {
+    match name {
+        "HOMESTEAD" => PrecompileSpecId::HOMESTEAD,
+        "BYZANTIUM" => PrecompileSpecId::BYZANTIUM,
+        "ISTANBUL" => PrecompileSpecId::ISTANBUL,
+        "BERLIN" => PrecompileSpecId::BERLIN,
+        "CANCUN" => PrecompileSpecId::CANCUN,
+        "PRAGUE" => PrecompileSpecId::PRAGUE,
+        "LATEST" => PrecompileSpecId::LATEST,
+        _ => panic!("unknown precompile spec {name}"),
+    }
+}
@@ -0,0 +1,229 @@
+use hex_literal::hex;
+use openvm_sha256_guest::sha256;
+use revm_precompile::{secp256r1, Bytes, B256};
+
+/// WebAuthn assertion as returned by `navigator.credentials.get()`,
+/// checked against the credential's uncompressed P-256 public key `x || y`.
+pub struct WebAuthnAssertion<'a> {
+    pub authenticator_data: &'a [u8],
+    pub client_data_json: &'a [u8],
+    /// ASN.1 DER encoded ECDSA signature
+    pub signature: &'a [u8],
+    pub public_key: &'a [u8; 64],
+}
+
+/// Verify a WebAuthn assertion with the p256_verify precompile.
+/// The signed message is `authenticatorData || sha256(clientDataJSON)`, hashed once more with sha256.
+/// Returns false for a signature that is not strict DER.
+pub fn verify_webauthn_assertion(assertion: &WebAuthnAssertion) -> bool {
+    let Some(signature) = der_signature_to_rs(assertion.signature) else {
+        return false;
+    };
+    let client_data_hash = sha256(assertion.client_data_json);
+    let message_hash = sha256(&[assertion.authenticator_data, &client_data_hash[..]].concat());
+
+    let mut input = [0u8; 160];
+    input[..32].copy_from_slice(&message_hash);
+    input[32..96].copy_from_slice(&signature);
+    input[96..].copy_from_slice(assertion.public_key);
+    let outcome = secp256r1::p256_verify(&Bytes::copy_from_slice(&input), u64::MAX).unwrap();
+    outcome.bytes == Bytes::from(B256::with_last_byte(1))
+}
+
+/// Convert a DER `SEQUENCE { INTEGER r, INTEGER s }` signature into fixed-width big-endian `r || s`.
+/// Rejects non-minimal encodings, negative integers, values wider than 32 bytes and trailing data.
+pub fn der_signature_to_rs(der: &[u8]) -> Option<[u8; 64]> {
+    let (&tag, rest) = der.split_first()?;
+    let (&len, body) = rest.split_first()?;
+    if tag != 0x30 || len >= 0x80 || body.len() != len as usize {
+        return None;
+    }
+    let (r, body) = der_integer(body)?;
+    let (s, body) = der_integer(body)?;
+    if !body.is_empty() {
+        return None;
+    }
+
+    let mut rs = [0u8; 64];
+    rs[32 - r.len()..32].copy_from_slice(r);
+    rs[64 - s.len()..].copy_from_slice(s);
+    Some(rs)
+}
+
+/// Parse one positive DER INTEGER of at most 32 value bytes.
+/// Returns its big-endian value without the sign padding byte, and the remaining input.
+fn der_integer(der: &[u8]) -> Option<(&[u8], &[u8])> {
+    let (&tag, rest) = der.split_first()?;
+    let (&len, rest) = rest.split_first()?;
+    if tag != 0x02 || len == 0 || len as usize > rest.len() {
+        return None;
+    }
+    let (value, rest) = rest.split_at(len as usize);
+    if value[0] & 0x80 != 0 {
+        return None;
+    }
+    let value = if value[0] == 0 && value.len() > 1 {
+        // a zero byte is only allowed to clear the sign bit of the next byte
+        if value[1] & 0x80 == 0 {
+            return None;
+        }
+        &value[1..]
+    } else {
+        value
+    };
+    (value.len() <= 32).then_some((value, rest))
+}
+
+/// Public key `x || y` of the credential that produced every assertion below
+const WEBAUTHN_PUBLIC_KEY: [u8; 64] = hex!("e7a238f4f03ba41a5eef416276b5b5652a93042273cadf62b0cc5fe9d88974393a8c400ec179c6138633bce90a0ee72cd29b181f3a1bb24221b8f4c4ffbf257b");
+
+/// Vector of test cases for WebAuthn assertion verification.
+/// Each test case consists of (authenticator_data, client_data_json, der_signature, expected_result).
+/// Assertions for rpId "example.com" produced by a software P-256 authenticator in Python (`cryptography`).
+/// All fixtures are synthetic; captured assertions run under the webauthn-fixtures feature.
+const WEBAUTHN_TEST_CASES: &[(&[u8], &[u8], &[u8], bool)] = &[
+    // 33-byte r, user present
+    (
+        &hex!("a379a6f6eeafb9a55e378c118034e2751e682fab9f2d30ab13d2125586ce19470500000001"),
+        br#"{"type":"webauthn.get","challenge":"rDL2UP41KBQ-HUXX9YTMuS-hROOYPGkfC1WlGW-gu1I","origin":"https://example.com","crossOrigin":false}"#,
+        &hex!("3045022100d3abfb5bb82d1a61429e8f07d19d3bcb07f6267731fecda5155816db5572f8fe022036dbff86dd2ffeb8c3e51fea9f9b53012ec2acba720474f6996ab68fb2403b74"),
+        true,
+    ),
+    // 32-byte r, 33-byte s
+    (
+        &hex!("a379a6f6eeafb9a55e378c118034e2751e682fab9f2d30ab13d2125586ce19470500000004"),
+        br#"{"type":"webauthn.get","challenge":"1zJkSCndNiBvmelyjMKpSR-y226d-POE4CehP8cXFDM","origin":"https://example.com","crossOrigin":false}"#,
+        &hex!("3045022025903e950b0f265ea973bc1d2670807e8b18682999c98832969e1c7c2ba553a8022100c7481a2aeb430348512eaa364076ffb98df3e823db2e3ff2dbbe7b8004f4c9e8"),
+        true,
+    ),
+    // 31-byte r, left-padded to 32 bytes
+    (
+        &hex!("a379a6f6eeafb9a55e378c118034e2751e682fab9f2d30ab13d2125586ce19470500000319"),
+        br#"{"type":"webauthn.get","challenge":"AjkHSJoCQwnd9TYliMu5QJz-e7KqrhIs_TT1irJ4O4Y","origin":"https://example.com","crossOrigin":false}"#,
+        &hex!("3043021f5b0ffe2fa023879536bc0fed247360a8c88bbc09241a810109af10106fa88402202c699bb3bfad40c6e970243e1c0ca5878f905cfeba12c0fa8d34fce853e8a5d8"),
+        true,
+    ),
+    // user present and verified, backup eligible and backed up
+    (
+        &hex!("a379a6f6eeafb9a55e378c118034e2751e682fab9f2d30ab13d2125586ce19471d000f4240"),
+        br#"{"type":"webauthn.get","challenge":"iqFOlq69Mu9ixliRGhD3fVoSGHq2GIcC7pi_8tJBR2Q","origin":"https://example.com","crossOrigin":false}"#,
+        &hex!("3046022100e89927ea06432461ea4349b960123dbb6f106794035f073dca809679fa63aac9022100de279ee18485b30a74e7ddb689ab17704a6d9f8b4aa8a67eaae3636254c085c3"),
+        true,
+    ),
+    // Invalid - tampered challenge in clientDataJSON
+    (
+        &hex!("a379a6f6eeafb9a55e378c118034e2751e682fab9f2d30ab13d2125586ce19470500000001"),
+        br#"{"type":"webauthn.get","challenge":"sDL2UP41KBQ-HUXX9YTMuS-hROOYPGkfC1WlGW-gu1I","origin":"https://example.com","crossOrigin":false}"#,
+        &hex!("3045022100d3abfb5bb82d1a61429e8f07d19d3bcb07f6267731fecda5155816db5572f8fe022036dbff86dd2ffeb8c3e51fea9f9b53012ec2acba720474f6996ab68fb2403b74"),
+        false,
+    ),
+    // Invalid - tampered origin in clientDataJSON
+    (
+        &hex!("a379a6f6eeafb9a55e378c118034e2751e682fab9f2d30ab13d2125586ce19470500000001"),
+        br#"{"type":"webauthn.get","challenge":"rDL2UP41KBQ-HUXX9YTMuS-hROOYPGkfC1WlGW-gu1I","origin":"https://evil.example","crossOrigin":false}"#,
+        &hex!("3045022100d3abfb5bb82d1a61429e8f07d19d3bcb07f6267731fecda5155816db5572f8fe022036dbff86dd2ffeb8c3e51fea9f9b53012ec2acba720474f6996ab68fb2403b74"),
+        false,
+    ),
+    // Invalid - user verified flag set in authenticatorData
+    (
+        &hex!("a379a6f6eeafb9a55e378c118034e2751e682fab9f2d30ab13d2125586ce19470d00000004"),
+        br#"{"type":"webauthn.get","challenge":"1zJkSCndNiBvmelyjMKpSR-y226d-POE4CehP8cXFDM","origin":"https://example.com","crossOrigin":false}"#,
+        &hex!("3045022025903e950b0f265ea973bc1d2670807e8b18682999c98832969e1c7c2ba553a8022100c7481a2aeb430348512eaa364076ffb98df3e823db2e3ff2dbbe7b8004f4c9e8"),
+        false,
+    ),
+    // Invalid - signature counter changed in authenticatorData
+    (
+        &hex!("a379a6f6eeafb9a55e378c118034e2751e682fab9f2d30ab13d2125586ce19470500000320"),
+        br#"{"type":"webauthn.get","challenge":"AjkHSJoCQwnd9TYliMu5QJz-e7KqrhIs_TT1irJ4O4Y","origin":"https://example.com","crossOrigin":false}"#,
+        &hex!("3043021f5b0ffe2fa023879536bc0fed247360a8c88bbc09241a810109af10106fa88402202c699bb3bfad40c6e970243e1c0ca5878f905cfeba12c0fa8d34fce853e8a5d8"),
+        false,
+    ),
+    // Invalid DER - r encoded as negative without its zero padding byte
+    (
+        &hex!("a379a6f6eeafb9a55e378c118034e2751e682fab9f2d30ab13d2125586ce19470500000001"),
+        br#"{"type":"webauthn.get","challenge":"rDL2UP41KBQ-HUXX9YTMuS-hROOYPGkfC1WlGW-gu1I","origin":"https://example.com","crossOrigin":false}"#,
+        &hex!("30440220d3abfb5bb82d1a61429e8f07d19d3bcb07f6267731fecda5155816db5572f8fe022036dbff86dd2ffeb8c3e51fea9f9b53012ec2acba720474f6996ab68fb2403b74"),
+        false,
+    ),
+    // Invalid DER - non-minimal zero padding of r
+    (
+        &hex!("a379a6f6eeafb9a55e378c118034e2751e682fab9f2d30ab13d2125586ce19470500000004"),
+        br#"{"type":"webauthn.get","challenge":"1zJkSCndNiBvmelyjMKpSR-y226d-POE4CehP8cXFDM","origin":"https://example.com","crossOrigin":false}"#,
+        &hex!("304602210025903e950b0f265ea973bc1d2670807e8b18682999c98832969e1c7c2ba553a8022100c7481a2aeb430348512eaa364076ffb98df3e823db2e3ff2dbbe7b8004f4c9e8"),
+        false,
+    ),
+    // Invalid DER - trailing byte after the sequence
+    (
+        &hex!("a379a6f6eeafb9a55e378c118034e2751e682fab9f2d30ab13d2125586ce19470500000319"),
+        br#"{"type":"webauthn.get","challenge":"AjkHSJoCQwnd9TYliMu5QJz-e7KqrhIs_TT1irJ4O4Y","origin":"https://example.com","crossOrigin":false}"#,
+        &hex!("3043021f5b0ffe2fa023879536bc0fed247360a8c88bbc09241a810109af10106fa88402202c699bb3bfad40c6e970243e1c0ca5878f905cfeba12c0fa8d34fce853e8a5d800"),
+        false,
+    ),
+    // Invalid DER - raw r || s instead of DER
+    (
+        &hex!("a379a6f6eeafb9a55e378c118034e2751e682fab9f2d30ab13d2125586ce19470500000001"),
+        br#"{"type":"webauthn.get","challenge":"rDL2UP41KBQ-HUXX9YTMuS-hROOYPGkfC1WlGW-gu1I","origin":"https://example.com","crossOrigin":false}"#,
+        &hex!("d3abfb5bb82d1a61429e8f07d19d3bcb07f6267731fecda5155816db5572f8fe36dbff86dd2ffeb8c3e51fea9f9b53012ec2acba720474f6996ab68fb2403b74"),
+        false,
+    ),
+];
+
+/// Vector of test cases for DER to r || s normalization.
+/// Each test case consists of (der_signature, expected r || s).
+const DER_SIGNATURE_TEST_CASES: &[(&[u8], [u8; 64])] = &[
+    // single-byte integers
+    (
+        &hex!("3006020101020102"),
+        hex!("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002"),
+    ),
+    // 33-byte r with sign padding
+    (
+        &hex!("3045022100d3abfb5bb82d1a61429e8f07d19d3bcb07f6267731fecda5155816db5572f8fe022036dbff86dd2ffeb8c3e51fea9f9b53012ec2acba720474f6996ab68fb2403b74"),
+        hex!("d3abfb5bb82d1a61429e8f07d19d3bcb07f6267731fecda5155816db5572f8fe36dbff86dd2ffeb8c3e51fea9f9b53012ec2acba720474f6996ab68fb2403b74"),
+    ),
+    // 31-byte r
+    (
+        &hex!("3043021f5b0ffe2fa023879536bc0fed247360a8c88bbc09241a810109af10106fa88402202c699bb3bfad40c6e970243e1c0ca5878f905cfeba12c0fa8d34fce853e8a5d8"),
+        hex!("005b0ffe2fa023879536bc0fed247360a8c88bbc09241a810109af10106fa8842c699bb3bfad40c6e970243e1c0ca5878f905cfeba12c0fa8d34fce853e8a5d8"),
+    ),
+];
+
+/// Vector of malformed DER signatures that must be rejected.
+const INVALID_DER_SIGNATURES: &[&[u8]] = &[
+    &hex!(""),
+    &hex!("30"),
+    // wrong sequence tag
+    &hex!("3106020101020102"),
+    // sequence length longer than the input
+    &hex!("3007020101020102"),
+    // wrong integer tag
+    &hex!("3006030101020102"),
+    // empty integer
+    &hex!("30050200020102"),
+    // integer length past the end of the sequence
+    &hex!("3006020101020202"),
+    // missing s
+    &hex!("3003020101"),
+    // r wider than 32 bytes
+    &hex!("3027022201000000000000000000000000000000000000000000000000000000000000000000020102"),
+];
+
+/// Run verification tests for WebAuthn assertions and DER signature normalization
+pub fn run_webauthn_tests() {
+    for (der, expected) in DER_SIGNATURE_TEST_CASES {
+        assert_eq!(der_signature_to_rs(der), Some(*expected));
+    }
+    for der in INVALID_DER_SIGNATURES {
+        assert_eq!(der_signature_to_rs(der), None);
+    }
+
+    for (authenticator_data, client_data_json, signature, expected) in WEBAUTHN_TEST_CASES {
+        let assertion = WebAuthnAssertion {
+            authenticator_data,
+            client_data_json,
+            signature,
+            public_key: &WEBAUTHN_PUBLIC_KEY,
+        };
+        assert_eq!(verify_webauthn_assertion(&assertion), *expected);
+    }
+}
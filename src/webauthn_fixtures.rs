@@ -0,0 +1,30 @@
+use openvm::io::read_vec;
+
+use crate::webauthn::{verify_webauthn_assertion, WebAuthnAssertion};
+
+/// Run captured WebAuthn ES256 assertions read from stdin, see `scripts/webauthn_inputs.py`.
+/// The input stream holds a 4-byte little-endian case count, then for each case the credential
+/// public key `x || y`, authenticatorData, clientDataJSON, DER signature and one byte that is 1
+/// if the assertion is valid.
+pub fn run_webauthn_fixture_tests() {
+    let count = u32::from_le_bytes(read_vec().try_into().expect("count must be 4 bytes"));
+    for index in 0..count {
+        let public_key: [u8; 64] = read_vec().try_into().expect("public key must be 64 bytes");
+        let authenticator_data = read_vec();
+        let client_data_json = read_vec();
+        let signature = read_vec();
+        let valid = read_vec() == [1];
+
+        let assertion = WebAuthnAssertion {
+            authenticator_data: &authenticator_data,
+            client_data_json: &client_data_json,
+            signature: &signature,
+            public_key: &public_key,
+        };
+        assert_eq!(
+            verify_webauthn_assertion(&assertion),
+            valid,
+            "webauthn fixture {index}"
+        );
+    }
+}